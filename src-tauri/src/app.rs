@@ -0,0 +1,771 @@
+// Tauriアプリ（コマンドとウィンドウ）。CLIからは使わないので gui フィーチャーでのみビルドする
+use tauri::{AppHandle, Emitter, Manager, Runtime};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+use std::process::Command;
+#[cfg(target_os = "linux")]
+use std::os::unix::fs::PermissionsExt;
+
+use crate::error::{AppError, ConflictReason, Target};
+use crate::project::{
+    self, content_token, ConfigReconcileReport, DuplicateResolution, ItemKind, Profile, ProfileChanges, Project,
+    Scope, SkillConflictInfo, ToggleChanges, ToggleTarget, TransferMode, TrashEntry,
+};
+use crate::{archive, atomic, discover, i18n, recent, sandbox, watcher};
+use crate::{Config, FileContent, Skill, SkillFile, SlashCommand};
+
+fn get_app_path() -> Option<PathBuf> {
+    // Linux: AppImageの場合、current_exeはマウント先の中を指すのでAppImage本体を使う
+    #[cfg(target_os = "linux")]
+    if let Some(appimage) = std::env::var_os("APPIMAGE") {
+        return Some(PathBuf::from(appimage));
+    }
+    std::env::current_exe().ok()
+}
+
+#[cfg(target_os = "macos")]
+fn get_app_bundle_path() -> Option<PathBuf> {
+    // SkillManager.app/Contents/MacOS/app → SkillManager.app
+    get_app_path()
+        .and_then(|p| p.parent().map(|p| p.to_path_buf()))  // MacOS/
+        .and_then(|p| p.parent().map(|p| p.to_path_buf()))  // Contents/
+        .and_then(|p| p.parent().map(|p| p.to_path_buf()))  // SkillManager.app/
+}
+
+#[cfg(target_os = "windows")]
+fn get_app_bundle_path() -> Option<PathBuf> {
+    // Windows: exe のあるディレクトリ
+    get_app_path()
+        .and_then(|p| p.parent().map(|p| p.to_path_buf()))
+}
+
+// 実行ファイルの場所から推定したプロジェクト（アプリを .claude/ に置いて使う場合）
+fn exe_base_dir(exe: &Path) -> Option<PathBuf> {
+    #[cfg(target_os = "macos")]
+    {
+        // macOS: SkillManager.app/Contents/MacOS/app → SkillManager.app の親ディレクトリ（.claude/を期待）
+        exe.ancestors().nth(4).map(|p| p.to_path_buf())
+    }
+    #[cfg(not(target_os = "macos"))]
+    {
+        // Windows・Linux: exe のあるディレクトリ自体が .claude/
+        exe.parent().map(|p| p.to_path_buf())
+    }
+}
+
+fn get_exe_base_dir() -> Option<PathBuf> {
+    get_app_path().and_then(|exe| exe_base_dir(&exe))
+}
+
+fn is_agent_dir(dir: &Path) -> bool {
+    dir.file_name().map(|n| n == ".claude" || n == ".codex").unwrap_or(false)
+}
+
+const ITEM_DIR_NAMES: [&str; 4] = ["skills", "disabled-skills", "commands", "disabled-commands"];
+
+const PROJECT_CHANGED_EVENT: &str = "project-changed";
+
+// 開いているプロジェクト。パスを扱う処理はすべてここを経由する
+static CURRENT_PROJECT: RwLock<Option<Project>> = RwLock::new(None);
+
+fn get_project() -> Option<Project> {
+    CURRENT_PROJECT.read().ok().and_then(|project| project.clone())
+}
+
+fn get_base_dir() -> Option<PathBuf> {
+    get_project().map(|p| p.base_dir().to_path_buf())
+}
+
+// 起動引数の --project <path> または最初のパス引数（相対パスは cwd から解決）
+fn project_arg(args: &[String], cwd: &Path) -> Option<PathBuf> {
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let path = if arg == "--project" {
+            args.next()?
+        } else if let Some(path) = arg.strip_prefix("--project=") {
+            path
+        } else if arg.starts_with('-') {
+            // macOSの -psn_... などは無視
+            continue;
+        } else {
+            arg
+        };
+        return Some(cwd.join(path));
+    }
+    None
+}
+
+fn project_from_args(args: &[String], cwd: &Path) -> Option<Project> {
+    let path = project_arg(args, cwd)?;
+    Project::open(&path).map_err(|e| log::warn!("{}", e)).ok()
+}
+
+fn initial_project() -> Option<Project> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let cwd = std::env::current_dir().unwrap_or_default();
+    project_from_args(&args, &cwd).or_else(|| get_exe_base_dir().filter(|dir| is_agent_dir(dir)).map(Project::new))
+}
+
+// 2つ目のインスタンスの起動引数（先頭は実行ファイル）からプロジェクトを決める
+// パスの指定がなければ、そのインスタンスの実行ファイルが置かれた .claude/ を使う
+fn project_from_second_instance(args: &[String], cwd: &Path) -> Option<Project> {
+    project_from_args(args.get(1..).unwrap_or_default(), cwd).or_else(|| {
+        args.first()
+            .and_then(|exe| exe_base_dir(&cwd.join(exe)))
+            .filter(|dir| is_agent_dir(dir))
+            .map(Project::new)
+    })
+}
+
+// プロジェクトを切り替え、監視対象と最近のプロジェクトを更新する
+fn set_project<R: Runtime>(app: &AppHandle<R>, project: Project) {
+    project.apply_locale();
+    if let Ok(mut current) = CURRENT_PROJECT.write() {
+        *current = Some(project.clone());
+    }
+
+    if let Some(watcher) = app.try_state::<watcher::WatcherState>() {
+        let app = app.clone();
+        let emit = move |changes: Vec<watcher::FsChange>| {
+            if let Err(e) = app.emit(watcher::CHANGE_EVENT, changes) {
+                log::warn!("Failed to emit {}: {}", watcher::CHANGE_EVENT, e);
+            }
+        };
+        if let Err(e) = watcher.watch(project.base_dir(), emit) {
+            log::warn!("{}", e);
+        }
+    }
+
+    match app.path().app_config_dir() {
+        Ok(config_dir) => {
+            if let Err(e) = recent::record(&config_dir, &project) {
+                log::warn!("Failed to save recent projects: {}", e);
+            }
+        }
+        Err(e) => log::warn!("Failed to resolve app config dir: {}", e),
+    }
+}
+
+fn current_project() -> Result<Project, AppError> {
+    get_project().ok_or_else(AppError::not_in_project)
+}
+
+fn is_in_claude_dir() -> bool {
+    get_project().is_some()
+}
+
+fn get_agent_type_internal() -> String {
+    get_project()
+        .map(|p| p.agent_type())
+        .unwrap_or_else(|| "none".to_string())
+}
+
+#[tauri::command]
+fn get_agent_type() -> String {
+    get_agent_type_internal()
+}
+
+#[tauri::command]
+fn get_available_agents() -> Vec<String> {
+    let mut available = Vec::new();
+
+    if let Some(base_dir) = get_base_dir() {
+        if let Some(project_root) = base_dir.parent() {
+            if project_root.join(".claude").exists() {
+                available.push("claude".to_string());
+            }
+            if project_root.join(".codex").exists() {
+                available.push("codex".to_string());
+            }
+        }
+    }
+
+    available
+}
+
+#[tauri::command]
+// 別プロセスでアプリを起動し直した場合はtrue（フロントエンド側で終了する）
+fn switch_agent_type(app: AppHandle, target: String) -> Result<bool, AppError> {
+    let current_type = get_agent_type_internal();
+    if current_type == target {
+        return Ok(false);
+    }
+
+    let target_dir_name = match target.as_str() {
+        "claude" => ".claude",
+        "codex" => ".codex",
+        _ => return Err(AppError::invalid(Target::Agent, target)),
+    };
+
+    // 現在のベースディレクトリの親（プロジェクトルート）を取得
+    let base_dir = get_base_dir().ok_or_else(AppError::not_in_project)?;
+    let project_root = base_dir.parent().ok_or_else(AppError::not_in_project)?;
+    let target_dir = project_root.join(target_dir_name);
+
+    // ターゲットディレクトリを作成
+    fs::create_dir_all(&target_dir).map_err(|e| AppError::io(&target_dir, e))?;
+
+    // .claude/ に置かれたアプリでなければ、コピーせずにこのウィンドウで切り替える
+    if get_exe_base_dir().as_deref() != Some(base_dir.as_path()) || !install_app(&target_dir)? {
+        set_project(&app, Project::new(target_dir));
+        return Ok(false);
+    }
+
+    Ok(true)
+}
+
+#[tauri::command]
+fn check_setup() -> Result<bool, AppError> {
+    // .claudeディレクトリ内にいるかチェック
+    Ok(is_in_claude_dir())
+}
+
+#[tauri::command]
+fn get_current_project_path() -> Option<String> {
+    if is_in_claude_dir() {
+        get_base_dir()
+            .and_then(|p| p.parent().map(|p| p.to_path_buf()))
+            .map(|p| p.to_string_lossy().to_string())
+    } else {
+        None
+    }
+}
+
+// path を省略するとカレントディレクトリ（ターミナルから起動した場所）を開く
+#[tauri::command]
+fn open_project(app: AppHandle, path: Option<String>, agent: Option<String>) -> Result<String, AppError> {
+    let mut path = match path {
+        Some(path) => PathBuf::from(path),
+        None => std::env::current_dir().map_err(|e| AppError::io(Path::new("."), e))?,
+    };
+    if let Some(agent) = agent {
+        path = path.join(project::agent_dir_name(&agent).ok_or_else(|| AppError::invalid(Target::Agent, agent.as_str()))?);
+    }
+    let project = Project::open(&path)?;
+    let root = project.project_root().ok_or_else(AppError::not_in_project)?.to_string_lossy().to_string();
+    set_project(&app, project);
+    Ok(root)
+}
+
+#[tauri::command]
+fn get_recent_projects(app: AppHandle) -> Vec<recent::RecentProject> {
+    app.path().app_config_dir().map(|dir| recent::load(&dir)).unwrap_or_default()
+}
+
+#[tauri::command]
+fn remove_recent_project(app: AppHandle, path: String) -> Result<(), AppError> {
+    let config_dir = app.path().app_config_dir().map_err(AppError::io_message)?;
+    recent::remove(&config_dir, &path).map_err(|e| AppError::io(&config_dir, e))
+}
+
+// root 以下（省略時は現在のプロジェクトのルート）の .claude / .codex を探す
+#[tauri::command]
+fn discover_agent_dirs(root: Option<String>) -> Result<Vec<discover::AgentDirSummary>, AppError> {
+    let root = match root {
+        Some(root) => PathBuf::from(root),
+        None => current_project()?
+            .project_root()
+            .ok_or_else(AppError::not_in_project)?
+            .to_path_buf(),
+    };
+    discover::discover_agent_dirs(&root)
+}
+
+#[tauri::command]
+// 別プロセスでアプリを起動した場合はtrue（フロントエンド側で終了する）
+fn copy_app_to_project(app: AppHandle, project_path: String) -> Result<bool, AppError> {
+    let target_dir = PathBuf::from(&project_path).join(".claude");
+
+    // .claudeディレクトリを作成
+    fs::create_dir_all(&target_dir).map_err(|e| AppError::io(&target_dir, e))?;
+
+    // 既にそこから起動している場合は、このウィンドウでそのプロジェクトを開く
+    if !install_app(&target_dir)? {
+        set_project(&app, Project::new(target_dir));
+        return Ok(false);
+    }
+    Ok(true)
+}
+
+// sh の1つの引数として埋め込む
+#[cfg(unix)]
+fn shell_quote(path: &Path) -> String {
+    format!("'{}'", path.to_string_lossy().replace('\'', r"'\''"))
+}
+
+fn is_same_file(a: &Path, b: &Path) -> bool {
+    matches!((a.canonicalize(), b.canonicalize()), (Ok(a), Ok(b)) if a == b)
+}
+
+// 実行中のアプリ（macOSは.appバンドル）を target_dir にコピーして起動する
+// コピー先が実行中のアプリ自身なら、消してしまわないよう何もせずにfalseを返す
+fn install_app(target_dir: &Path) -> Result<bool, AppError> {
+    #[cfg(target_os = "macos")]
+    let app_path = get_app_bundle_path();
+    // Windows: exe、Linux: AppImage（なければ実行ファイル）
+    #[cfg(not(target_os = "macos"))]
+    let app_path = get_app_path();
+
+    let app_path = app_path.ok_or_else(|| AppError::not_found(Target::App, "skillsmanager"))?;
+    let app_name = app_path.file_name().ok_or_else(|| AppError::not_found(Target::App, "skillsmanager"))?;
+    let target_app = target_dir.join(app_name);
+
+    if is_same_file(&app_path, &target_app) {
+        return Ok(false);
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        if target_app.exists() {
+            fs::remove_dir_all(&target_app).map_err(|e| AppError::io(&target_app, e))?;
+        }
+
+        crate::copy_dir_all(&app_path, &target_app).map_err(|e| AppError::io(&app_path, e))?;
+
+        Command::new("open")
+            .arg(&target_app)
+            .spawn()
+            .map_err(|e| AppError::io(&target_app, e))?;
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        if target_app.exists() {
+            fs::remove_file(&target_app).map_err(|e| AppError::io(&target_app, e))?;
+        }
+
+        fs::copy(&app_path, &target_app).map_err(|e| AppError::io(&app_path, e))?;
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        Command::new(&target_app)
+            .spawn()
+            .map_err(|e| AppError::io(&target_app, e))?;
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        fs::set_permissions(&target_app, fs::Permissions::from_mode(0o755))
+            .map_err(|e| AppError::io(&target_app, e))?;
+
+        // 起動元のAppImageの環境変数を引き継がない
+        Command::new(&target_app)
+            .env_remove("APPIMAGE")
+            .env_remove("APPDIR")
+            .spawn()
+            .map_err(|e| AppError::io(&target_app, e))?;
+    }
+
+    Ok(true)
+}
+
+#[tauri::command]
+fn load_skills() -> Result<Vec<Skill>, AppError> {
+    current_project()?.load_skills()
+}
+
+#[tauri::command]
+fn validate_skills() -> Result<Vec<project::SkillDiagnostic>, AppError> {
+    current_project()?.validate_skills()
+}
+
+#[tauri::command]
+fn create_skill(
+    name: String,
+    description: String,
+    category: String,
+    with_scripts: bool,
+    with_references: bool,
+    with_assets: bool,
+) -> Result<(), AppError> {
+    current_project()?.create_skill(&name, &description, &category, with_scripts, with_references, with_assets)
+}
+
+#[tauri::command]
+fn toggle_skill(skill_name: String, enabled: bool, scope: Option<Scope>) -> Result<(), AppError> {
+    current_project()?.toggle_skill(&skill_name, enabled, scope.unwrap_or_default())
+}
+
+// 有効・無効の両方にある同名のスキル・コマンドを1つにする（toggle_* の Conflict への対応）
+#[tauri::command]
+fn resolve_duplicate(
+    kind: ItemKind,
+    name: String,
+    scope: Option<Scope>,
+    resolution: DuplicateResolution,
+) -> Result<(), AppError> {
+    current_project()?.resolve_duplicate(kind, &name, scope.unwrap_or_default(), resolution)
+}
+
+// 複数のスキル・コマンドをまとめて切り替える（途中で失敗したら何も変更しない）
+#[tauri::command]
+fn toggle_many(skills: Vec<ToggleTarget>, commands: Vec<ToggleTarget>, enabled: bool) -> Result<ToggleChanges, AppError> {
+    current_project()?.toggle_many(&skills, &commands, enabled)
+}
+
+#[tauri::command]
+fn transfer_skill(skill_name: String, from: Scope, to: Scope, mode: TransferMode, force: bool) -> Result<(), AppError> {
+    current_project()?.transfer_skill(&skill_name, from, to, mode, force)
+}
+
+#[tauri::command]
+fn rename_skill(old_name: String, new_name: String) -> Result<(), AppError> {
+    current_project()?.rename_skill(&old_name, &new_name)
+}
+
+#[tauri::command]
+fn duplicate_skill(skill_name: String, new_name: String, enabled: bool) -> Result<(), AppError> {
+    current_project()?.duplicate_skill(&skill_name, &new_name, enabled)
+}
+
+#[tauri::command]
+fn delete_skill(skill_name: String) -> Result<TrashEntry, AppError> {
+    current_project()?.delete_skill(&skill_name)
+}
+
+#[tauri::command]
+fn load_slash_commands() -> Result<Vec<SlashCommand>, AppError> {
+    current_project()?.load_slash_commands()
+}
+
+#[tauri::command]
+fn toggle_slash_command(command_name: String, enabled: bool, scope: Option<Scope>) -> Result<(), AppError> {
+    current_project()?.toggle_slash_command(&command_name, enabled, scope.unwrap_or_default())
+}
+
+#[tauri::command]
+fn rename_slash_command(old_name: String, new_name: String) -> Result<(), AppError> {
+    current_project()?.rename_slash_command(&old_name, &new_name)
+}
+
+#[tauri::command]
+fn delete_slash_command(command_name: String) -> Result<TrashEntry, AppError> {
+    current_project()?.delete_slash_command(&command_name)
+}
+
+#[tauri::command]
+fn load_config() -> Result<Config, AppError> {
+    current_project()?.load_config()
+}
+
+#[tauri::command]
+fn save_config(config: Config) -> Result<(), AppError> {
+    current_project()?.save_config(&config)
+}
+
+#[tauri::command]
+fn save_profile(name: String) -> Result<Profile, AppError> {
+    current_project()?.save_profile(&name)
+}
+
+#[tauri::command]
+fn delete_profile(name: String) -> Result<(), AppError> {
+    current_project()?.delete_profile(&name)
+}
+
+#[tauri::command]
+fn apply_profile(name: String) -> Result<ProfileChanges, AppError> {
+    current_project()?.apply_profile(&name)
+}
+
+#[tauri::command]
+fn reconcile_config(fix: bool) -> Result<ConfigReconcileReport, AppError> {
+    current_project()?.reconcile_config(fix)
+}
+
+#[tauri::command]
+fn check_skill_conflict(skill_name: String, enabled: bool) -> Result<SkillConflictInfo, AppError> {
+    current_project()?.check_skill_conflict(&skill_name, enabled)
+}
+
+#[tauri::command]
+fn copy_skill_to_other_agent(skill_name: String, enabled: bool, force: bool) -> Result<(), AppError> {
+    let project = current_project()?;
+    let agent_type = project.agent_type();
+    let target_agent = match agent_type.as_str() {
+        "claude" => "codex",
+        "codex" => "claude",
+        _ => return Err(AppError::invalid(Target::Agent, agent_type)),
+    };
+    project.copy_skill_to_agent(&skill_name, enabled, target_agent, force)
+}
+
+#[tauri::command]
+fn list_trash() -> Result<Vec<TrashEntry>, AppError> {
+    Ok(current_project()?.list_trash())
+}
+
+#[tauri::command]
+fn restore_from_trash(id: String) -> Result<TrashEntry, AppError> {
+    current_project()?.restore_from_trash(&id)
+}
+
+#[tauri::command]
+fn empty_trash() -> Result<(), AppError> {
+    current_project()?.empty_trash()
+}
+
+#[tauri::command]
+fn import_skill_archive(archive_path: String, enabled: bool, force: bool) -> Result<String, AppError> {
+    current_project()?.import_skill_archive(&PathBuf::from(&archive_path), enabled, force)
+}
+
+#[tauri::command]
+fn export_skills(
+    app: tauri::AppHandle,
+    skill_names: Vec<String>,
+    output_path: String,
+    overwrite: bool,
+) -> Result<archive::ExportManifest, AppError> {
+    let version = app.package_info().version.to_string();
+    current_project()?.export_skills(&skill_names, &PathBuf::from(&output_path), &version, overwrite)
+}
+
+#[tauri::command]
+fn can_show_command_button() -> bool {
+    // .claudeの時のみ、かつskillsmanager.mdがcommands/にもdisabled-commands/にも存在しない場合のみtrue
+    let agent_type = get_agent_type_internal();
+    if agent_type != "claude" {
+        return false;
+    }
+
+    if let Some(base_dir) = get_base_dir() {
+        let commands_path = base_dir.join("commands").join("skillsmanager.md");
+        let disabled_path = base_dir.join("disabled-commands").join("skillsmanager.md");
+        return !commands_path.exists() && !disabled_path.exists();
+    }
+
+    false
+}
+
+#[tauri::command]
+fn copy_app_to_commands() -> Result<(), AppError> {
+    let project = current_project()?;
+    let commands_dir = project.commands_dir();
+    let locale = project.load_config()?.locale.unwrap_or_else(i18n::current);
+
+    // commandsフォルダがなければ作成
+    if !commands_dir.exists() {
+        fs::create_dir_all(&commands_dir).map_err(|e| AppError::io(&commands_dir, e))?;
+    }
+
+    // .claude/ に置かれたアプリでなければ、インストール先のアプリにこのプロジェクトを渡して起動する
+    #[cfg(any(target_os = "macos", target_os = "windows", target_os = "linux"))]
+    let installed = get_exe_base_dir().as_deref() != Some(project.base_dir());
+
+    // OS別の起動コマンド（allowed-tools と実行する行）
+    #[cfg(target_os = "macos")]
+    let launch = Some((
+        "Bash(open:*)",
+        if installed {
+            // open で起動したアプリにはカレントディレクトリが引き継がれない
+            let app_bundle = get_app_bundle_path().ok_or_else(|| AppError::not_found(Target::App, "skillsmanager"))?;
+            format!("open -n -a {} --args --project \"$PWD\"", shell_quote(&app_bundle))
+        } else {
+            "open .claude/skillsmanager.app".to_string()
+        },
+    ));
+
+    #[cfg(target_os = "windows")]
+    let launch = Some((
+        "Bash(powershell:*)",
+        if installed {
+            let exe_path = get_app_path().ok_or_else(|| AppError::not_found(Target::App, "skillsmanager"))?;
+            let exe_path = exe_path.to_string_lossy().replace('\'', "''");
+            format!(r#"powershell -Command "Start-Process -FilePath '{}' -ArgumentList '--project','.'""#, exe_path)
+        } else {
+            r#"powershell -Command "Start-Process -FilePath '.\.claude\skillsmanager.exe'""#.to_string()
+        },
+    ));
+
+    #[cfg(target_os = "linux")]
+    let launch = {
+        let app_path = get_app_path().ok_or_else(|| AppError::not_found(Target::App, "skillsmanager"))?;
+        let command = if installed {
+            format!("{} --project .", shell_quote(&app_path))
+        } else {
+            // .claude/ に置かれているAppImage（または実行ファイル）の名前
+            let app_name = app_path.file_name().ok_or_else(|| AppError::not_found(Target::App, "skillsmanager"))?;
+            shell_quote(&Path::new("./.claude").join(app_name))
+        };
+        Some(("Bash(nohup:*)", format!("nohup {} >/dev/null 2>&1 &", command)))
+    };
+
+    #[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+    let launch: Option<(&str, String)> = None;
+
+    let text = |key| i18n::text(locale, key);
+    let md_content = match launch {
+        Some((allowed_tools, command)) => format!(
+            "---\ndescription: {}\nallowed-tools: {}\n---\n\n{}\n\n!`{}`\n\n{}\n",
+            text(i18n::Key::LauncherDescription),
+            allowed_tools,
+            text(i18n::Key::LauncherHeading),
+            command,
+            text(i18n::Key::LauncherFooter)
+        ),
+        None => format!(
+            "---\ndescription: {}\n---\n\n{}\n\n{}\n",
+            text(i18n::Key::LauncherDescription),
+            text(i18n::Key::LauncherManual),
+            text(i18n::Key::LauncherFooter)
+        ),
+    };
+
+    let md_path = commands_dir.join("skillsmanager.md");
+    atomic::write(&md_path, md_content).map_err(|e| AppError::io(&md_path, e))?;
+
+    Ok(())
+}
+
+// webviewから渡されたパスを、現在のプロジェクト内に限定して解決する
+// ~/.claude は認証情報なども含むので、スキル・コマンドのフォルダだけを許可する（~/.claude 自体を開いている場合も同じ）
+fn resolve_project_path(path: &str) -> Result<PathBuf, AppError> {
+    let project = current_project()?;
+    let item_dirs = |dir: &Path| ITEM_DIR_NAMES.map(|name| dir.join(name));
+    let mut roots = Vec::new();
+    if project.is_user_level() {
+        roots.extend(item_dirs(project.base_dir()));
+    } else {
+        roots.push(project.base_dir().to_path_buf());
+    }
+    if let Some(user_dir) = project.user_dir() {
+        roots.extend(item_dirs(&user_dir));
+    }
+    Ok(sandbox::resolve(&roots, &project.base_dir().join(path))?)
+}
+
+#[tauri::command]
+fn read_file(path: String) -> Result<FileContent, AppError> {
+    let path = resolve_project_path(&path)?;
+    let content = fs::read_to_string(&path).map_err(|e| AppError::io(&path, e))?;
+    let token = content_token(content.as_bytes());
+    Ok(FileContent { content, token })
+}
+
+#[tauri::command]
+fn write_file(path: String, content: String, expected_token: Option<String>) -> Result<String, AppError> {
+    let path = resolve_project_path(&path)?;
+
+    if let Some(expected) = expected_token {
+        match fs::read_to_string(&path) {
+            Ok(current) => {
+                let token = content_token(current.as_bytes());
+                if token != expected {
+                    return Err(AppError::conflict(
+                        Target::File,
+                        path.to_string_lossy(),
+                        ConflictReason::ModifiedOnDisk { current: FileContent { content: current, token } },
+                    ));
+                }
+            }
+            // 読み込み後に削除された場合はそのまま作り直す
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(AppError::io(&path, e)),
+        }
+    }
+
+    atomic::write(&path, &content).map_err(|e| AppError::io(&path, e))?;
+    Ok(content_token(content.as_bytes()))
+}
+
+#[tauri::command]
+fn list_directory(path: String) -> Result<Vec<SkillFile>, AppError> {
+    let dir_path = resolve_project_path(&path)?;
+    if !dir_path.is_dir() {
+        return Err(AppError::not_found(Target::Directory, path));
+    }
+
+    Ok(project::list_files(&dir_path))
+}
+
+#[cfg_attr(mobile, tauri::mobile_entry_point)]
+pub fn run() {
+    tauri::Builder::default()
+        .plugin(tauri_plugin_single_instance::init(|app, args, cwd| {
+            // 別のプロジェクトを指定して起動された（または別の .claude/ のアプリから起動された）場合は切り替える
+            if let Some(project) = project_from_second_instance(&args, Path::new(&cwd)) {
+                let is_current = get_base_dir().as_deref() == Some(project.base_dir());
+                if !is_current {
+                    let root = project.project_root().map(|p| p.to_string_lossy().to_string());
+                    set_project(app, project);
+                    if let Err(e) = app.emit(PROJECT_CHANGED_EVENT, root) {
+                        log::warn!("Failed to emit {}: {}", PROJECT_CHANGED_EVENT, e);
+                    }
+                }
+            }
+
+            // 既存のウィンドウにフォーカス
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.unminimize();
+                let _ = window.set_focus();
+            }
+        }))
+        .plugin(tauri_plugin_clipboard_manager::init())
+        .plugin(tauri_plugin_dialog::init())
+        .plugin(tauri_plugin_process::init())
+        .setup(|app| {
+            if cfg!(debug_assertions) {
+                app.handle().plugin(
+                    tauri_plugin_log::Builder::default()
+                        .level(log::LevelFilter::Info)
+                        .build(),
+                )?;
+            }
+
+            // skills/・commands/ などの変更をフロントエンドに通知する
+            app.manage(watcher::WatcherState::default());
+            if let Some(project) = initial_project() {
+                set_project(app.handle(), project);
+            }
+            Ok(())
+        })
+        .invoke_handler(tauri::generate_handler![
+            load_skills,
+            validate_skills,
+            create_skill,
+            toggle_skill,
+            toggle_many,
+            resolve_duplicate,
+            transfer_skill,
+            rename_skill,
+            duplicate_skill,
+            delete_skill,
+            load_slash_commands,
+            toggle_slash_command,
+            rename_slash_command,
+            delete_slash_command,
+            load_config,
+            save_config,
+            reconcile_config,
+            save_profile,
+            delete_profile,
+            apply_profile,
+            read_file,
+            write_file,
+            list_directory,
+            check_setup,
+            get_current_project_path,
+            open_project,
+            get_recent_projects,
+            remove_recent_project,
+            discover_agent_dirs,
+            copy_app_to_project,
+            get_agent_type,
+            get_available_agents,
+            switch_agent_type,
+            check_skill_conflict,
+            copy_skill_to_other_agent,
+            list_trash,
+            restore_from_trash,
+            empty_trash,
+            import_skill_archive,
+            export_skills,
+            can_show_command_button,
+            copy_app_to_commands
+        ])
+        .run(tauri::generate_context!())
+        .expect("error while running tauri application");
+}
@@ -0,0 +1,359 @@
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use walkdir::WalkDir;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+use crate::error::{AppError, Target};
+use crate::frontmatter;
+use crate::i18n::{self, Key};
+use crate::validate;
+
+// 展開後の合計サイズの上限（zip bomb対策）
+const MAX_EXTRACTED_SIZE: u64 = 256 * 1024 * 1024;
+
+// macOSのzipに入る不要ファイル
+fn is_ignored(path: &Path) -> bool {
+    path.components().any(|c| match c {
+        Component::Normal(name) => name == "__MACOSX" || name == ".DS_Store",
+        _ => false,
+    })
+}
+
+fn is_skill_md(path: &Path) -> bool {
+    path.file_name()
+        .map(|n| n.to_string_lossy().eq_ignore_ascii_case("skill.md"))
+        .unwrap_or(false)
+}
+
+pub struct ArchiveSkill {
+    pub name: String,
+    // アーカイブ内のスキルのルート（空ならアーカイブ直下）
+    root: PathBuf,
+    archive: ZipArchive<fs::File>,
+    entries: Vec<(usize, PathBuf)>,
+}
+
+// アーカイブを開いてSKILL.mdのあるフォルダを探す（直下かラッパーフォルダ1段まで）
+pub fn open(archive_path: &Path) -> Result<ArchiveSkill, AppError> {
+    let invalid = |message: String| AppError::parse(Target::Archive, Some(archive_path), message);
+    let locale = i18n::current();
+    let file = fs::File::open(archive_path).map_err(|e| AppError::io(archive_path, e))?;
+    let mut archive = ZipArchive::new(file).map_err(|e| invalid(e.to_string()))?;
+
+    let mut entries = Vec::new();
+    for index in 0..archive.len() {
+        let entry = archive.by_index(index).map_err(|e| invalid(e.to_string()))?;
+        // enclosed_nameは絶対パスや ".." を含むパスを弾く（zip-slip対策）
+        let path = entry
+            .enclosed_name()
+            .ok_or_else(|| invalid(i18n::format(locale, Key::ArchiveUnsafePath, &[("name", entry.name())])))?;
+        if entry.is_symlink() {
+            return Err(invalid(i18n::format(locale, Key::ArchiveSymlink, &[("name", entry.name())])));
+        }
+        if is_ignored(&path) {
+            continue;
+        }
+        entries.push((index, path));
+    }
+
+    let mut roots: Vec<PathBuf> = entries
+        .iter()
+        .filter(|(_, path)| is_skill_md(path) && path.components().count() <= 2)
+        .map(|(_, path)| path.parent().map(Path::to_path_buf).unwrap_or_default())
+        .collect();
+    roots.sort_by_key(|root| root.components().count());
+
+    let root = match roots.as_slice() {
+        [] => return Err(AppError::not_found(Target::File, "SKILL.md")),
+        [root] => root.clone(),
+        [first, second, ..] if first.components().count() < second.components().count() => first.clone(),
+        _ => return Err(invalid(i18n::text(locale, Key::ArchiveMultipleSkills).to_string())),
+    };
+
+    // frontmatterのname、なければフォルダ名（直下にSKILL.mdがある場合はファイル名）
+    // "repo-main/SKILL.md" のようなラッパーフォルダの名前よりもnameを優先する
+    let skill_md_index = entries
+        .iter()
+        .find(|(_, path)| path.parent() == Some(root.as_path()) && is_skill_md(path))
+        .map(|(index, _)| *index);
+    let from_frontmatter = skill_md_index.and_then(|index| {
+        let mut content = String::new();
+        let mut entry = archive.by_index(index).ok()?;
+        io::Read::read_to_string(&mut entry, &mut content).ok()?;
+        frontmatter::parse(&content).ok()?.frontmatter?.name
+    });
+    let name = from_frontmatter
+        .filter(|name| validate::is_valid_skill_name(name))
+        .or_else(|| root.file_name().map(|folder| folder.to_string_lossy().to_string()))
+        .unwrap_or_else(|| {
+            archive_path
+                .file_stem()
+                .map(|s| s.to_string_lossy().to_string())
+                .unwrap_or_default()
+        });
+
+    if !validate::is_valid_skill_name(&name) {
+        return Err(AppError::invalid(Target::Skill, name));
+    }
+
+    Ok(ArchiveSkill { name, root, archive, entries })
+}
+
+impl ArchiveSkill {
+    // スキルのルート以下だけを dst に展開する
+    pub fn extract(self, dst: &Path) -> Result<(), AppError> {
+        self.extract_with_limit(dst, MAX_EXTRACTED_SIZE)
+    }
+
+    fn extract_with_limit(mut self, dst: &Path, limit: u64) -> Result<(), AppError> {
+        self.extract_entries(dst, limit).map_err(|e| {
+            // 展開途中のものは新規作成分なので削除する
+            let _ = fs::remove_dir_all(dst);
+            match e.kind() {
+                // 上限超えや壊れた圧縮データ
+                io::ErrorKind::InvalidData => AppError::parse(Target::Archive, None, e),
+                _ => AppError::io(dst, e),
+            }
+        })
+    }
+
+    fn extract_entries(&mut self, dst: &Path, limit: u64) -> io::Result<()> {
+        fs::create_dir_all(dst)?;
+        // ヘッダーのサイズは偽れるので、実際に書き出した量で数える
+        let mut remaining = limit;
+        for (index, path) in &self.entries {
+            let Ok(relative) = path.strip_prefix(&self.root) else { continue };
+            if relative.as_os_str().is_empty() {
+                continue;
+            }
+            let target = dst.join(relative);
+
+            let mut entry = self.archive.by_index(*index).map_err(io::Error::other)?;
+            if entry.is_dir() {
+                fs::create_dir_all(&target)?;
+            } else {
+                if let Some(parent) = target.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                let mut out = fs::File::create(&target)?;
+                let written = io::copy(&mut io::Read::take(&mut entry, remaining + 1), &mut out)?;
+                if written > remaining {
+                    let max = (limit / 1024 / 1024).to_string();
+                    let message = i18n::format(i18n::current(), Key::ArchiveTooLarge, &[("max", &max)]);
+                    return Err(io::Error::new(io::ErrorKind::InvalidData, message));
+                }
+                remaining -= written;
+
+                // scripts/ の実行権限などを戻す（setuidなどは落とす）
+                #[cfg(unix)]
+                if let Some(mode) = entry.unix_mode() {
+                    use std::os::unix::fs::PermissionsExt;
+                    fs::set_permissions(&target, fs::Permissions::from_mode(mode & 0o777))?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+pub const MANIFEST_FILE_NAME: &str = "manifest.json";
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportedFile {
+    pub path: String,
+    pub sha256: String,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportedSkill {
+    pub name: String,
+    pub description: String,
+    pub enabled: bool,
+    pub files: Vec<ExportedFile>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportManifest {
+    pub exporter: String,
+    pub exporter_version: String,
+    pub exported_at: String,
+    pub skills: Vec<ExportedSkill>,
+}
+
+pub struct ExportSource {
+    pub name: String,
+    pub description: String,
+    pub enabled: bool,
+    pub dir: PathBuf,
+}
+
+// アーカイブ内のパスは常に "/" 区切り
+fn archive_path(relative: &Path) -> String {
+    relative
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+// skills/ と同じ <name>/SKILL.md の構成でまとめ、直下にmanifest.jsonを置く
+// overwrite=false なら既存のファイルには書き込まない
+pub fn export(
+    sources: &[ExportSource],
+    output: &Path,
+    exporter_version: &str,
+    overwrite: bool,
+) -> Result<ExportManifest, AppError> {
+    let file = fs::OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .create_new(!overwrite)
+        .open(output)
+        .map_err(|e| match e.kind() {
+            io::ErrorKind::AlreadyExists => {
+                AppError::already_exists(Target::File, output.file_name().unwrap_or_default().to_string_lossy(), output)
+            }
+            _ => AppError::io(output, e),
+        })?;
+    let mut writer = ZipWriter::new(file);
+
+    let result = write_skills(&mut writer, sources, exporter_version).and_then(|manifest| {
+        let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+        let json = serde_json::to_string_pretty(&manifest)?;
+        writer.start_file(MANIFEST_FILE_NAME, options).map_err(io::Error::other)?;
+        io::Write::write_all(&mut writer, json.as_bytes())?;
+        writer.finish().map_err(io::Error::other)?;
+        Ok(manifest)
+    });
+
+    result.map_err(|e| {
+        let _ = fs::remove_file(output);
+        AppError::io(output, e)
+    })
+}
+
+fn write_skills(
+    writer: &mut ZipWriter<fs::File>,
+    sources: &[ExportSource],
+    exporter_version: &str,
+) -> io::Result<ExportManifest> {
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    let mut skills = Vec::new();
+
+    for source in sources {
+        let mut files = Vec::new();
+        for entry in WalkDir::new(&source.dir).sort_by_file_name() {
+            let entry = entry.map_err(io::Error::other)?;
+            let relative = entry.path().strip_prefix(&source.dir).map_err(io::Error::other)?;
+            let path = archive_path(&Path::new(&source.name).join(relative));
+
+            if entry.file_type().is_dir() {
+                writer.add_directory(path, options).map_err(io::Error::other)?;
+            } else if entry.file_type().is_file() {
+                let content = fs::read(entry.path())?;
+                // 実行権限を展開時に戻せるよう、パーミッションも記録する
+                #[cfg(unix)]
+                let options = {
+                    use std::os::unix::fs::PermissionsExt;
+                    options.unix_permissions(entry.metadata().map_err(io::Error::other)?.permissions().mode())
+                };
+                writer.start_file(path.as_str(), options).map_err(io::Error::other)?;
+                io::Write::write_all(writer, &content)?;
+                files.push(ExportedFile { path, sha256: format!("{:x}", Sha256::digest(&content)) });
+            }
+        }
+
+        skills.push(ExportedSkill {
+            name: source.name.clone(),
+            description: source.description.clone(),
+            enabled: source.enabled,
+            files,
+        });
+    }
+
+    Ok(ExportManifest {
+        exporter: "skillsmanager".to_string(),
+        exporter_version: exporter_version.to_string(),
+        exported_at: chrono::Local::now().to_rfc3339(),
+        skills,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorDetail;
+
+    fn fixture(name: &str) -> PathBuf {
+        PathBuf::from(format!("{}/tests/fixtures/archive/{}", env!("CARGO_MANIFEST_DIR"), name))
+    }
+
+    // 危険なエントリ（evil.txt）を名指しで弾いているか
+    fn is_rejected(name: &str) -> bool {
+        match open(&fixture(name)) {
+            Err(AppError { detail: ErrorDetail::Parse { detail, .. }, .. }) => detail.contains("evil.txt"),
+            _ => false,
+        }
+    }
+
+    #[test]
+    fn rejects_parent_and_absolute_entries() {
+        assert!(is_rejected("zip-slip.zip"));
+        assert!(is_rejected("absolute-path.zip"));
+    }
+
+    #[test]
+    fn prefers_frontmatter_name_over_wrapper_folder() {
+        let skill = open(&fixture("wrapper-folder.zip")).unwrap();
+        assert_eq!(skill.name, "sample");
+
+        let tmp = tempfile::tempdir().unwrap();
+        let dst = tmp.path().join("sample");
+        skill.extract(&dst).unwrap();
+        assert!(dst.join("SKILL.md").is_file());
+        assert!(dst.join("scripts/run.sh").is_file());
+    }
+
+    #[test]
+    fn stops_extracting_past_the_size_limit() {
+        let skill = open(&fixture("wrapper-folder.zip")).unwrap();
+        let tmp = tempfile::tempdir().unwrap();
+        let dst = tmp.path().join("sample");
+
+        let result = skill.extract_with_limit(&dst, 1);
+        assert!(matches!(result, Err(AppError { detail: ErrorDetail::Parse { .. }, .. })));
+        assert!(!dst.exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn keeps_executable_bit_through_export_and_import() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path().join("skills/sample");
+        fs::create_dir_all(dir.join("scripts")).unwrap();
+        fs::write(dir.join("SKILL.md"), "---\nname: sample\ndescription: d\n---\n").unwrap();
+        fs::write(dir.join("scripts/run.sh"), "#!/bin/sh\n").unwrap();
+        fs::set_permissions(dir.join("scripts/run.sh"), fs::Permissions::from_mode(0o755)).unwrap();
+
+        let output = tmp.path().join("skills.zip");
+        let source = ExportSource { name: "sample".to_string(), description: "d".to_string(), enabled: true, dir };
+        export(&[source], &output, "test", false).unwrap();
+
+        let dst = tmp.path().join("imported");
+        open(&output).unwrap().extract(&dst).unwrap();
+        let mode = fs::metadata(dst.join("scripts/run.sh")).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o755);
+        let mode = fs::metadata(dst.join("SKILL.md")).unwrap().permissions().mode();
+        assert_eq!(mode & 0o111, 0);
+    }
+}
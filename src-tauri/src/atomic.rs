@@ -0,0 +1,54 @@
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+fn temp_path(path: &Path) -> io::Result<PathBuf> {
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "invalid path"))?;
+    Ok(path.with_file_name(format!(
+        ".{}.{}.tmp",
+        file_name.to_string_lossy(),
+        std::process::id()
+    )))
+}
+
+// 同じディレクトリの一時ファイルに書いてfsyncしてからrenameする
+// 途中でクラッシュしても元のファイルか新しいファイルのどちらかが残る
+pub fn write(path: &Path, contents: impl AsRef<[u8]>) -> io::Result<()> {
+    let temp = temp_path(path)?;
+
+    if let Err(e) = write_and_replace(&temp, path, contents.as_ref()) {
+        let _ = fs::remove_file(&temp);
+        return Err(e);
+    }
+
+    sync_parent(path);
+    Ok(())
+}
+
+fn write_and_replace(temp: &Path, path: &Path, contents: &[u8]) -> io::Result<()> {
+    let mut file = fs::File::create(temp)?;
+    file.write_all(contents)?;
+    file.sync_all()?;
+    drop(file);
+
+    // 既存ファイルのパーミッションを引き継ぐ
+    if let Ok(metadata) = fs::metadata(path) {
+        fs::set_permissions(temp, metadata.permissions())?;
+    }
+    fs::rename(temp, path)
+}
+
+// renameをディスクに反映する（Windowsではディレクトリを開けないので何もしない）
+#[cfg(unix)]
+fn sync_parent(path: &Path) {
+    if let Some(parent) = path.parent() {
+        if let Ok(dir) = fs::File::open(parent) {
+            let _ = dir.sync_all();
+        }
+    }
+}
+
+#[cfg(not(unix))]
+fn sync_parent(_path: &Path) {}
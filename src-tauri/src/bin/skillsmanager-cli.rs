@@ -0,0 +1,467 @@
+// GUIを起動せずにスキルを操作するためのCLI（CIやシェルスクリプト用）
+use clap::{Parser, Subcommand, ValueEnum};
+use serde::Serialize;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use skillsmanager_lib::discover::discover_agent_dirs;
+use skillsmanager_lib::error::{AppError, Target};
+use skillsmanager_lib::project::{Config, DuplicateResolution, ItemKind, Project, Scope, Severity, ToggleTarget};
+
+#[derive(Parser)]
+#[command(name = "skillsmanager-cli", version, about = "Skills Managerのコマンドライン版")]
+struct Cli {
+    /// プロジェクトのルート、または .claude/・.codex/ ディレクトリ（省略時はカレントディレクトリ）
+    #[arg(long, global = true)]
+    project: Option<PathBuf>,
+
+    /// 結果をJSONで出力する
+    #[arg(long, global = true)]
+    json: bool,
+
+    #[command(subcommand)]
+    command: Commands,
+}
+
+#[derive(Subcommand)]
+enum Commands {
+    #[command(flatten)]
+    Project(ProjectCommand),
+    /// --project 以下の .claude/・.codex/ を探してスキル・コマンドの数を表示する
+    Discover,
+}
+
+// .claude/・.codex/ を開いてから実行するサブコマンド
+#[derive(Subcommand)]
+enum ProjectCommand {
+    /// スキルとスラッシュコマンドの一覧
+    List,
+    /// スキル（--commandならスラッシュコマンド）を有効にする
+    Enable {
+        #[arg(required = true)]
+        names: Vec<String>,
+        #[arg(long)]
+        command: bool,
+        /// ~/.claude のスキル・コマンドを対象にする
+        #[arg(long)]
+        user: bool,
+    },
+    /// スキル（--commandならスラッシュコマンド）を無効にする
+    Disable {
+        #[arg(required = true)]
+        names: Vec<String>,
+        #[arg(long)]
+        command: bool,
+        /// ~/.claude のスキル・コマンドを対象にする
+        #[arg(long)]
+        user: bool,
+    },
+    /// skills/ と disabled-skills/ の両方にある同名のスキル（--commandならコマンド）を1つにする
+    ResolveDuplicate {
+        name: String,
+        /// 残す方（mergeは無効側にしかないファイルを有効側にコピーする。スキルのみ）
+        #[arg(long, value_enum)]
+        keep: Keep,
+        #[arg(long)]
+        command: bool,
+        /// ~/.claude のスキル・コマンドを対象にする
+        #[arg(long)]
+        user: bool,
+    },
+    /// カテゴリとその中のスキル・コマンド
+    Categories,
+    /// SKILL.mdを検証する（エラーがあれば終了コード1）
+    Validate,
+    /// スキルを別のエージェント（claude / codex）にコピーする
+    CopyTo {
+        #[arg(long)]
+        agent: String,
+        #[arg(required = true)]
+        names: Vec<String>,
+        /// 同名のスキルがあればゴミ箱に移して上書きする
+        #[arg(long)]
+        force: bool,
+    },
+    /// スキルをzipに書き出す（名前を省略すると全スキル）
+    Export {
+        #[arg(long, short)]
+        output: PathBuf,
+        names: Vec<String>,
+        /// 出力先のファイルがあれば上書きする
+        #[arg(long)]
+        force: bool,
+    },
+    /// プロファイルの一覧
+    Profiles,
+    /// 現在有効なスキル・コマンドをプロファイルとして保存する
+    SaveProfile { name: String },
+    /// プロファイルのスキル・コマンドだけを有効にする（失敗したら何も変更しない）
+    ApplyProfile { name: String },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Keep {
+    Enabled,
+    Disabled,
+    Merge,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ListedItem {
+    kind: &'static str,
+    scope: Scope,
+    // 同名のプロジェクトの項目に上書きされている
+    shadowed: bool,
+    // 有効・無効の両方に同名のものがある
+    duplicate: bool,
+    name: String,
+    description: String,
+    enabled: bool,
+    categories: Vec<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ListedCategory {
+    name: String,
+    skills: Vec<String>,
+    commands: Vec<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Resolved {
+    name: String,
+    kind: ItemKind,
+    resolution: DuplicateResolution,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Toggled {
+    name: String,
+    enabled: bool,
+    // false: 既にその状態だった
+    changed: bool,
+}
+
+fn categories_of(categories: &indexmap::IndexMap<String, Vec<String>>, name: &str) -> Vec<String> {
+    categories
+        .iter()
+        .filter(|(_, names)| names.iter().any(|n| n == name))
+        .map(|(category, _)| category.clone())
+        .collect()
+}
+
+fn print_json<T: Serialize>(value: &T) -> Result<(), AppError> {
+    let json = serde_json::to_string_pretty(value).map_err(AppError::io_message)?;
+    println!("{}", json);
+    Ok(())
+}
+
+fn list(project: &Project, config: &Config, json: bool) -> Result<(), AppError> {
+    let mut items: Vec<ListedItem> = project
+        .load_skills()?
+        .into_iter()
+        .map(|skill| ListedItem {
+            kind: "skill",
+            scope: skill.scope,
+            shadowed: skill.shadowed,
+            duplicate: skill.duplicate,
+            categories: categories_of(&config.categories, &skill.name),
+            name: skill.name,
+            description: skill.description,
+            enabled: skill.enabled,
+        })
+        .collect();
+    if config.load_slash_commands {
+        items.extend(project.load_slash_commands()?.into_iter().map(|command| ListedItem {
+            kind: "command",
+            scope: command.scope,
+            shadowed: command.shadowed,
+            duplicate: command.duplicate,
+            categories: categories_of(&config.command_categories, &command.name),
+            name: command.name,
+            description: command.description,
+            enabled: command.enabled,
+        }));
+    }
+
+    if json {
+        return print_json(&items);
+    }
+    for item in items {
+        let name = if item.kind == "command" { format!("/{}", item.name) } else { item.name };
+        let scope = match (item.scope, item.shadowed) {
+            (Scope::Project, _) => "",
+            (Scope::User, false) => " (user)",
+            (Scope::User, true) => " (user, shadowed)",
+        };
+        let duplicate = if item.duplicate { " (duplicate)" } else { "" };
+        println!(
+            "{} {:<32} [{}] {}",
+            if item.enabled { "✓" } else { "-" },
+            format!("{}{}{}", name, scope, duplicate),
+            item.categories.join(", "),
+            item.description
+        );
+    }
+    Ok(())
+}
+
+fn toggle(project: &Project, names: &[String], command: bool, scope: Scope, enabled: bool, json: bool) -> Result<(), AppError> {
+    // 存在しない名前が混ざっていたり途中で失敗したりしたら何も変更しない
+    let targets: Vec<ToggleTarget> = names.iter().map(|name| ToggleTarget { name: name.clone(), scope }).collect();
+    let changes = if command {
+        project.toggle_many(&[], &targets, enabled)?.commands
+    } else {
+        project.toggle_many(&targets, &[], enabled)?.skills
+    };
+    let toggled: Vec<Toggled> = names
+        .iter()
+        .map(|name| Toggled { name: name.clone(), enabled, changed: changes.contains(name) })
+        .collect();
+
+    if json {
+        return print_json(&toggled);
+    }
+    for item in toggled {
+        let unchanged = if item.changed { "" } else { " (unchanged)" };
+        println!("{} {}{}", if item.enabled { "enabled " } else { "disabled" }, item.name, unchanged);
+    }
+    Ok(())
+}
+
+fn categories(config: &Config, json: bool) -> Result<(), AppError> {
+    let listed: Vec<ListedCategory> = config
+        .category_order
+        .iter()
+        .map(|name| ListedCategory {
+            name: name.clone(),
+            skills: config.categories.get(name).cloned().unwrap_or_default(),
+            commands: config.command_categories.get(name).cloned().unwrap_or_default(),
+        })
+        .collect();
+
+    if json {
+        return print_json(&listed);
+    }
+    for category in listed {
+        println!("{}", category.name);
+        for skill in &category.skills {
+            println!("  {}", skill);
+        }
+        for command in &category.commands {
+            println!("  /{}", command);
+        }
+    }
+    Ok(())
+}
+
+fn validate(project: &Project, json: bool) -> Result<bool, AppError> {
+    let diagnostics = project.validate_skills()?;
+    let has_errors = diagnostics.iter().any(|d| d.severity == Severity::Error);
+
+    if json {
+        print_json(&diagnostics)?;
+    } else {
+        for d in &diagnostics {
+            let location = match d.line {
+                Some(line) => format!("{}:{}", d.file, line),
+                None => d.file.clone(),
+            };
+            let severity = if d.severity == Severity::Error { "error" } else { "warning" };
+            println!("{}: {} [{}] {}", severity, location, d.rule, d.message);
+        }
+    }
+    Ok(!has_errors)
+}
+
+fn copy_to(project: &Project, agent: &str, names: &[String], force: bool, json: bool) -> Result<(), AppError> {
+    let skills = project.load_skills()?;
+    let mut copied = Vec::new();
+    for name in names {
+        let skill = skills
+            .iter()
+            .find(|s| &s.name == name && s.scope == Scope::Project)
+            .ok_or_else(|| AppError::not_found(Target::Skill, name.as_str()))?;
+        project.copy_skill_to_agent(name, skill.enabled, agent, force)?;
+        copied.push(name.clone());
+    }
+
+    if json {
+        return print_json(&copied);
+    }
+    for name in copied {
+        println!("copied {} to {}", name, agent);
+    }
+    Ok(())
+}
+
+fn export(
+    project: &Project,
+    names: Vec<String>,
+    output: &std::path::Path,
+    force: bool,
+    json: bool,
+) -> Result<(), AppError> {
+    let names = if names.is_empty() {
+        project
+            .load_skills()?
+            .into_iter()
+            .filter(|s| s.scope == Scope::Project)
+            .map(|s| s.name)
+            .collect()
+    } else {
+        names
+    };
+    let manifest = project.export_skills(&names, output, env!("CARGO_PKG_VERSION"), force)?;
+
+    if json {
+        return print_json(&manifest);
+    }
+    println!("exported {} skill(s) to {}", manifest.skills.len(), output.display());
+    Ok(())
+}
+
+fn profiles(config: &Config, json: bool) -> Result<(), AppError> {
+    if json {
+        return print_json(&config.profiles);
+    }
+    for (name, profile) in &config.profiles {
+        println!("{}", name);
+        for skill in &profile.skills {
+            println!("  {}", skill);
+        }
+        for command in &profile.commands {
+            println!("  /{}", command);
+        }
+    }
+    Ok(())
+}
+
+fn apply_profile(project: &Project, name: &str, json: bool) -> Result<(), AppError> {
+    let changes = project.apply_profile(name)?;
+
+    if json {
+        return print_json(&changes);
+    }
+    for skill in &changes.enabled_skills {
+        println!("enabled  {}", skill);
+    }
+    for skill in &changes.disabled_skills {
+        println!("disabled {}", skill);
+    }
+    for command in &changes.enabled_commands {
+        println!("enabled  /{}", command);
+    }
+    for command in &changes.disabled_commands {
+        println!("disabled /{}", command);
+    }
+    for skill in &changes.missing_skills {
+        eprintln!("warning: {}", AppError::not_found(Target::Skill, skill.as_str()));
+    }
+    for command in &changes.missing_commands {
+        eprintln!("warning: {}", AppError::not_found(Target::Command, command.as_str()));
+    }
+    Ok(())
+}
+
+fn discover(root: &std::path::Path, json: bool) -> Result<(), AppError> {
+    let found = discover_agent_dirs(root)?;
+
+    if json {
+        return print_json(&found);
+    }
+    for dir in found {
+        println!(
+            "{:<48} skills {}/{}  commands {}/{}",
+            dir.path,
+            dir.enabled_skills,
+            dir.enabled_skills + dir.disabled_skills,
+            dir.enabled_commands,
+            dir.enabled_commands + dir.disabled_commands
+        );
+    }
+    Ok(())
+}
+
+fn scope_of(user: bool) -> Scope {
+    if user { Scope::User } else { Scope::Project }
+}
+
+fn run(cli: Cli) -> Result<bool, AppError> {
+    let root = match cli.project {
+        Some(path) => path,
+        None => std::env::current_dir().map_err(AppError::io_message)?,
+    };
+    let json = cli.json;
+    // discover はプロジェクトでないディレクトリ（モノレポのルートなど）でも使える
+    let command = match cli.command {
+        Commands::Discover => {
+            discover(&root, json)?;
+            return Ok(true);
+        }
+        Commands::Project(command) => command,
+    };
+    let project = Project::open(&root)?;
+    project.apply_locale();
+
+    match command {
+        ProjectCommand::List => list(&project, &project.load_config()?, json)?,
+        ProjectCommand::Enable { names, command, user } => {
+            toggle(&project, &names, command, scope_of(user), true, json)?
+        }
+        ProjectCommand::Disable { names, command, user } => {
+            toggle(&project, &names, command, scope_of(user), false, json)?
+        }
+        ProjectCommand::ResolveDuplicate { name, keep, command, user } => {
+            let kind = if command { ItemKind::Command } else { ItemKind::Skill };
+            let resolution = match keep {
+                Keep::Enabled => DuplicateResolution::KeepEnabled,
+                Keep::Disabled => DuplicateResolution::KeepDisabled,
+                Keep::Merge => DuplicateResolution::Merge,
+            };
+            project.resolve_duplicate(kind, &name, scope_of(user), resolution)?;
+            if json {
+                print_json(&Resolved { name, kind, resolution })?;
+            } else {
+                println!("resolved {}", name);
+            }
+        }
+        ProjectCommand::Categories => categories(&project.load_config()?, json)?,
+        ProjectCommand::Validate => return validate(&project, json),
+        ProjectCommand::CopyTo { agent, names, force } => copy_to(&project, &agent, &names, force, json)?,
+        ProjectCommand::Export { output, names, force } => export(&project, names, &output, force, json)?,
+        ProjectCommand::Profiles => profiles(&project.load_config()?, json)?,
+        ProjectCommand::SaveProfile { name } => {
+            let profile = project.save_profile(&name)?;
+            if json {
+                print_json(&profile)?;
+            } else {
+                println!("saved {} ({} skill(s), {} command(s))", name, profile.skills.len(), profile.commands.len());
+            }
+        }
+        ProjectCommand::ApplyProfile { name } => apply_profile(&project, &name, json)?,
+    }
+    Ok(true)
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let json = cli.json;
+    match run(cli) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        // --json のときはエラーも code 付きのJSONで返す（スクリプトから分岐できるように）
+        Err(e) if json => {
+            eprintln!("{}", serde_json::to_string_pretty(&e).unwrap_or_else(|_| e.message.clone()));
+            ExitCode::FAILURE
+        }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
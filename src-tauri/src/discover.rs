@@ -0,0 +1,144 @@
+// モノレポ内の .claude/ ・ .codex/ ディレクトリを探す
+use ignore::WalkBuilder;
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+
+use crate::error::{AppError, Target};
+use crate::project::Project;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentDirSummary {
+    pub path: String,          // .claude / .codex ディレクトリ
+    pub project_root: String,  // その親ディレクトリ
+    pub agent: String,         // "claude" / "codex"
+    pub enabled_skills: usize,
+    pub disabled_skills: usize,
+    pub enabled_commands: usize,
+    pub disabled_commands: usize,
+}
+
+fn is_agent_dir_name(name: &str) -> bool {
+    name == ".claude" || name == ".codex"
+}
+
+// SKILL.md を含むサブディレクトリの数
+fn count_skills(dir: &Path) -> usize {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return 0;
+    };
+    entries
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().is_dir())
+        .filter(|e| {
+            fs::read_dir(e.path())
+                .map(|files| {
+                    files
+                        .filter_map(|f| f.ok())
+                        .any(|f| f.file_name().to_string_lossy().eq_ignore_ascii_case("skill.md"))
+                })
+                .unwrap_or(false)
+        })
+        .count()
+}
+
+// .md ファイルの数（サブディレクトリは無視）
+fn count_commands(dir: &Path) -> usize {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return 0;
+    };
+    entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.is_file() && p.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("md")))
+        .count()
+}
+
+fn summarize(dir: &Path) -> AgentDirSummary {
+    let project = Project::new(dir.to_path_buf());
+    AgentDirSummary {
+        path: dir.to_string_lossy().to_string(),
+        project_root: project
+            .project_root()
+            .map(|p| p.to_string_lossy().to_string())
+            .unwrap_or_default(),
+        agent: project.agent_type(),
+        enabled_skills: count_skills(&project.skills_dir()),
+        disabled_skills: count_skills(&project.disabled_skills_dir()),
+        enabled_commands: count_commands(&project.commands_dir()),
+        disabled_commands: count_commands(&project.disabled_commands_dir()),
+    }
+}
+
+// root 以下を .gitignore に従って歩き、node_modules と .git は飛ばす。
+// .claude / .codex の中までは探さない
+pub fn discover_agent_dirs(root: &Path) -> Result<Vec<AgentDirSummary>, AppError> {
+    let root = root.canonicalize().map_err(|e| AppError::io(root, e))?;
+    if !root.is_dir() {
+        return Err(AppError::not_found(Target::Directory, root.to_string_lossy()));
+    }
+
+    let walker = WalkBuilder::new(&root)
+        .hidden(false)
+        .require_git(false)
+        .filter_entry(|entry| {
+            let name = entry.file_name().to_string_lossy();
+            if name == "node_modules" || name == ".git" {
+                return false;
+            }
+            !entry
+                .path()
+                .parent()
+                .and_then(|p| p.file_name())
+                .is_some_and(|n| is_agent_dir_name(&n.to_string_lossy()))
+        })
+        .build();
+
+    let mut found = Vec::new();
+    for entry in walker.filter_map(|e| e.ok()) {
+        let is_dir = entry.file_type().is_some_and(|t| t.is_dir());
+        if is_dir && is_agent_dir_name(&entry.file_name().to_string_lossy()) {
+            found.push(summarize(entry.path()));
+        }
+    }
+    found.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(found)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(path: &Path, content: &str) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn finds_nested_agent_dirs_and_skips_ignored() {
+        let tmp = tempfile::tempdir().unwrap();
+        let root = tmp.path();
+        write(&root.join(".gitignore"), "build/\n");
+        write(&root.join(".claude/skills/a/SKILL.md"), "");
+        write(&root.join(".claude/skills/b/SKILL.md"), "");
+        write(&root.join(".claude/disabled-skills/c/SKILL.md"), "");
+        write(&root.join(".claude/commands/run.md"), "");
+        write(&root.join("packages/web/.codex/skills/d/SKILL.md"), "");
+        write(&root.join("packages/web/.codex/disabled-commands/old.md"), "");
+        write(&root.join("node_modules/pkg/.claude/skills/x/SKILL.md"), "");
+        write(&root.join("build/.claude/skills/y/SKILL.md"), "");
+
+        let found = discover_agent_dirs(root).unwrap();
+        let root = root.canonicalize().unwrap();
+
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].path, root.join(".claude").to_string_lossy());
+        assert_eq!(found[0].agent, "claude");
+        assert_eq!((found[0].enabled_skills, found[0].disabled_skills), (2, 1));
+        assert_eq!((found[0].enabled_commands, found[0].disabled_commands), (1, 0));
+        assert_eq!(found[1].project_root, root.join("packages/web").to_string_lossy());
+        assert_eq!(found[1].agent, "codex");
+        assert_eq!((found[1].enabled_skills, found[1].disabled_commands), (1, 1));
+    }
+}
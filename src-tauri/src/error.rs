@@ -0,0 +1,241 @@
+use serde::Serialize;
+use std::fmt;
+use std::io;
+use std::path::Path;
+
+use crate::i18n::{self, Key, Locale};
+use crate::project::{DuplicateResolution, ItemKind};
+use crate::FileContent;
+
+// 何についてのエラーか
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Target {
+    Skill,
+    Command,
+    Profile,
+    Config,
+    File,
+    Directory,
+    Archive,
+    // .claude / .codex ディレクトリ
+    AgentDir,
+    // "claude" / "codex"
+    Agent,
+    TrashEntry,
+    // 実行中のアプリ本体
+    App,
+    Description,
+    Category,
+    Resolution,
+}
+
+impl From<ItemKind> for Target {
+    fn from(kind: ItemKind) -> Self {
+        match kind {
+            ItemKind::Skill => Target::Skill,
+            ItemKind::Command => Target::Command,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "reason", rename_all = "camelCase")]
+pub enum ConflictReason {
+    // 作成先・移動先に同名のものが既にある
+    AlreadyExists { path: String },
+    // 有効・無効の両方に同名のものがあって切り替えられない
+    #[serde(rename_all = "camelCase")]
+    EnabledAndDisabled {
+        enabled_path: String,
+        disabled_path: String,
+        resolutions: Vec<DuplicateResolution>,
+    },
+    // read_file以降に他のエディタやClaude Codeが書き換えた
+    ModifiedOnDisk { current: FileContent },
+}
+
+// code（バリアント名）とフィールド名はフロントエンドやCLIの利用者が分岐に使うので変えない
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "code")]
+pub enum ErrorDetail {
+    // プロジェクトが開かれていない、または path に .claude / .codex がない
+    NotInProject { path: Option<String> },
+    NotFound { target: Target, name: String },
+    Conflict {
+        target: Target,
+        name: String,
+        #[serde(flatten)]
+        reason: Box<ConflictReason>,
+    },
+    Io { path: Option<String>, detail: String },
+    Parse {
+        target: Target,
+        path: Option<String>,
+        detail: String,
+        // 読めなかったファイルを退避した先
+        backup: Option<String>,
+    },
+    // プロジェクト外のパスや、OSに拒否されたパス
+    PermissionDenied { path: String },
+    InvalidInput { target: Target, value: String },
+}
+
+impl ErrorDetail {
+    pub fn message(&self, locale: Locale) -> String {
+        let label = |target: &Target| i18n::target_label(locale, *target);
+        let fill = |key: Key, args: &[(&str, &str)]| i18n::format(locale, key, args);
+        match self {
+            ErrorDetail::NotInProject { path: None } => i18n::text(locale, Key::NotInProject).to_string(),
+            ErrorDetail::NotInProject { path: Some(path) } => fill(Key::NoAgentDir, &[("path", path)]),
+            ErrorDetail::NotFound { target: Target::AgentDir, name } => fill(Key::AgentDirNotFound, &[("name", name)]),
+            ErrorDetail::NotFound { target: Target::File | Target::Directory, name } => {
+                fill(Key::PathNotFound, &[("name", name)])
+            }
+            ErrorDetail::NotFound { target, name } => fill(Key::NotFound, &[("target", label(target)), ("name", name)]),
+            ErrorDetail::Conflict { target, name, reason } => {
+                let key = match reason.as_ref() {
+                    ConflictReason::AlreadyExists { .. } => Key::AlreadyExists,
+                    ConflictReason::EnabledAndDisabled { .. } => Key::EnabledAndDisabled,
+                    ConflictReason::ModifiedOnDisk { .. } => Key::ModifiedOnDisk,
+                };
+                fill(key, &[("target", label(target)), ("name", name)])
+            }
+            ErrorDetail::Io { path: Some(path), detail } => format!("{}: {}", path, detail),
+            ErrorDetail::Io { path: None, detail } => detail.clone(),
+            ErrorDetail::Parse { target, path, detail, backup } => {
+                let mut text = match path {
+                    Some(path) => fill(Key::ParseWithPath, &[("path", path), ("message", detail)]),
+                    None => fill(Key::Parse, &[("target", label(target)), ("message", detail)]),
+                };
+                if let Some(backup) = backup {
+                    text.push_str(&fill(Key::Backup, &[("path", backup)]));
+                }
+                text
+            }
+            ErrorDetail::PermissionDenied { path } => fill(Key::PermissionDenied, &[("path", path)]),
+            ErrorDetail::InvalidInput { target, value } => match target {
+                Target::Skill => i18n::text(locale, Key::InvalidSkillName).to_string(),
+                Target::Profile if value.is_empty() => i18n::text(locale, Key::EmptyProfileName).to_string(),
+                Target::Description | Target::Category if value.is_empty() => {
+                    fill(Key::EmptyValue, &[("target", label(target))])
+                }
+                _ => fill(Key::InvalidValue, &[("target", label(target)), ("value", value)]),
+            },
+        }
+    }
+}
+
+impl fmt::Display for ErrorDetail {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message(i18n::current()))
+    }
+}
+
+// 表示用の message（作成時の言語）を添えたエラー。tauriコマンドやCLIはこれを返す
+#[derive(Debug, Clone, Serialize)]
+pub struct AppError {
+    #[serde(flatten)]
+    pub detail: ErrorDetail,
+    pub message: String,
+}
+
+impl From<ErrorDetail> for AppError {
+    fn from(detail: ErrorDetail) -> Self {
+        let message = detail.to_string();
+        AppError { detail, message }
+    }
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for AppError {}
+
+fn path_string(path: &Path) -> String {
+    path.to_string_lossy().to_string()
+}
+
+impl AppError {
+    pub fn not_in_project() -> Self {
+        ErrorDetail::NotInProject { path: None }.into()
+    }
+
+    pub fn no_agent_dir(path: &Path) -> Self {
+        ErrorDetail::NotInProject { path: Some(path_string(path)) }.into()
+    }
+
+    pub fn not_found(target: impl Into<Target>, name: impl Into<String>) -> Self {
+        ErrorDetail::NotFound { target: target.into(), name: name.into() }.into()
+    }
+
+    pub fn already_exists(target: impl Into<Target>, name: impl Into<String>, path: &Path) -> Self {
+        ErrorDetail::Conflict {
+            target: target.into(),
+            name: name.into(),
+            reason: Box::new(ConflictReason::AlreadyExists { path: path_string(path) }),
+        }
+        .into()
+    }
+
+    pub fn conflict(target: impl Into<Target>, name: impl Into<String>, reason: ConflictReason) -> Self {
+        ErrorDetail::Conflict { target: target.into(), name: name.into(), reason: Box::new(reason) }.into()
+    }
+
+    // OSにアクセスを拒否された場合は PermissionDenied にする
+    pub fn io(path: &Path, e: io::Error) -> Self {
+        if e.kind() == io::ErrorKind::PermissionDenied {
+            return Self::permission_denied(path);
+        }
+        ErrorDetail::Io { path: Some(path_string(path)), detail: e.to_string() }.into()
+    }
+
+    pub fn io_message(message: impl fmt::Display) -> Self {
+        ErrorDetail::Io { path: None, detail: message.to_string() }.into()
+    }
+
+    pub fn parse(target: Target, path: Option<&Path>, message: impl fmt::Display) -> Self {
+        ErrorDetail::Parse { target, path: path.map(path_string), detail: message.to_string(), backup: None }.into()
+    }
+
+    pub fn permission_denied(path: &Path) -> Self {
+        ErrorDetail::PermissionDenied { path: path_string(path) }.into()
+    }
+
+    pub fn invalid(target: Target, value: impl Into<String>) -> Self {
+        ErrorDetail::InvalidInput { target, value: value.into() }.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn serializes_code_and_context_fields() {
+        let err = AppError::not_found(Target::Profile, "docs");
+        assert_eq!(
+            serde_json::to_value(&err).unwrap(),
+            json!({ "code": "NotFound", "target": "profile", "name": "docs", "message": err.message })
+        );
+        assert_eq!(err.detail.message(Locale::Ja), "プロファイル「docs」が見つかりません");
+        assert_eq!(err.detail.message(Locale::En), "The profile \"docs\" was not found");
+
+        let err = AppError::already_exists(ItemKind::Skill, "review", Path::new("/p/.claude/skills/review"));
+        let value = serde_json::to_value(&err).unwrap();
+        assert_eq!(value["code"], "Conflict");
+        assert_eq!(value["target"], "skill");
+        assert_eq!(value["reason"], "alreadyExists");
+        assert_eq!(value["path"], "/p/.claude/skills/review");
+
+        // 詳細は表示用の message と別のキーに入る
+        let err = AppError::parse(Target::Config, None, "key must be a string");
+        let value = serde_json::to_value(&err).unwrap();
+        assert_eq!(value["detail"], "key must be a string");
+        assert_eq!(value["message"], err.message.as_str());
+    }
+}
@@ -0,0 +1,415 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::fmt;
+
+use crate::i18n::{self, Key};
+
+// SKILL.md / コマンドファイル共通のfrontmatter
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all(serialize = "camelCase", deserialize = "kebab-case"))]
+pub struct Frontmatter {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_tool_list")]
+    pub allowed_tools: Option<Vec<String>>,
+    #[serde(default, deserialize_with = "deserialize_text")]
+    pub model: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_text")]
+    pub argument_hint: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_text")]
+    pub license: Option<String>,
+    #[serde(default)]
+    pub metadata: Option<IndexMap<String, serde_json::Value>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FrontmatterError {
+    pub message: String,
+    // ファイル先頭からの行番号（1始まり）
+    pub line: Option<usize>,
+}
+
+impl fmt::Display for FrontmatterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => {
+                let line = line.to_string();
+                f.write_str(&i18n::format(i18n::current(), Key::AtLine, &[("line", &line), ("message", &self.message)]))
+            }
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+// frontmatterと本文に分割した結果
+pub struct Document<'a> {
+    pub frontmatter: Option<Frontmatter>,
+    // 型が合わずに読み飛ばしたフィールド（他のフィールドは読めている）
+    pub field_errors: Vec<FrontmatterError>,
+    pub body: &'a str,
+    // 本文の開始行（1始まり）
+    pub body_line: usize,
+}
+
+// ファイルを読み込んだ結果（一覧表示用）
+pub struct Summary {
+    pub frontmatter: Option<Frontmatter>,
+    pub description: String,
+    pub error: Option<String>,
+}
+
+// allowed-toolsは "Bash(git:*), Read" のような文字列とYAMLリストの両方を受け付ける
+fn deserialize_tool_list<'de, D>(deserializer: D) -> Result<Option<Vec<String>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum ToolList {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    Ok(match Option::<ToolList>::deserialize(deserializer)? {
+        None => None,
+        Some(ToolList::Many(tools)) => Some(tools),
+        Some(ToolList::One(tools)) => Some(split_tool_list(&tools)),
+    })
+}
+
+// argument-hint: [message] のようにYAMLのリストとして読まれる値も文字列として受け付ける
+fn deserialize_text<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    fn scalar(value: &serde_yaml::Value) -> Option<String> {
+        match value {
+            serde_yaml::Value::String(s) => Some(s.clone()),
+            serde_yaml::Value::Number(n) => Some(n.to_string()),
+            serde_yaml::Value::Bool(b) => Some(b.to_string()),
+            _ => None,
+        }
+    }
+
+    let value = serde_yaml::Value::deserialize(deserializer)?;
+    match &value {
+        serde_yaml::Value::Null => Ok(None),
+        serde_yaml::Value::Sequence(items) => {
+            let items: Option<Vec<String>> = items.iter().map(scalar).collect();
+            items
+                .map(|items| Some(format!("[{}]", items.join(", "))))
+                .ok_or_else(|| serde::de::Error::custom("expected a string or a list of strings"))
+        }
+        _ => scalar(&value)
+            .map(Some)
+            .ok_or_else(|| serde::de::Error::custom("expected a string or a list of strings")),
+    }
+}
+
+// カンマ区切り、またはスペース区切り（括弧内のスペースは区切らない）
+fn split_tool_list(tools: &str) -> Vec<String> {
+    let separator_is_comma = tools.contains(',');
+    let mut result = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0usize;
+
+    for c in tools.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+        let is_separator = depth == 0
+            && if separator_is_comma { c == ',' } else { c.is_whitespace() };
+        if is_separator {
+            if !current.trim().is_empty() {
+                result.push(current.trim().to_string());
+            }
+            current.clear();
+        } else {
+            current.push(c);
+        }
+    }
+    if !current.trim().is_empty() {
+        result.push(current.trim().to_string());
+    }
+    result
+}
+
+fn is_delimiter(line: &str) -> bool {
+    line.trim_end() == "---"
+}
+
+fn is_closing_delimiter(line: &str) -> bool {
+    let trimmed = line.trim_end();
+    trimmed == "---" || trimmed == "..."
+}
+
+// 先頭の "---" から次の "---" までをfrontmatterとして解析する
+pub fn parse(content: &str) -> Result<Document<'_>, FrontmatterError> {
+    let content = content.strip_prefix('\u{feff}').unwrap_or(content);
+
+    let mut lines = content.split_inclusive('\n');
+    let first = match lines.next() {
+        Some(line) if is_delimiter(line) => line,
+        _ => {
+            return Ok(Document { frontmatter: None, field_errors: Vec::new(), body: content, body_line: 1 });
+        }
+    };
+
+    let yaml_start = first.len();
+    let mut offset = yaml_start;
+    let mut line_count = 1;
+    for line in lines {
+        line_count += 1;
+        if is_closing_delimiter(line) {
+            let yaml = &content[yaml_start..offset];
+            let body = &content[offset + line.len()..];
+            let (frontmatter, field_errors) = parse_yaml(yaml)?;
+            return Ok(Document {
+                frontmatter: Some(frontmatter),
+                field_errors,
+                body,
+                body_line: line_count + 1,
+            });
+        }
+        offset += line.len();
+    }
+
+    Err(FrontmatterError {
+        message: i18n::t(Key::FrontmatterNotClosed).to_string(),
+        line: Some(1),
+    })
+}
+
+fn yaml_error(e: serde_yaml::Error) -> FrontmatterError {
+    // serde_yamlのメッセージ末尾の位置情報はYAMLブロック内の行なので落とす
+    let mut message = e.to_string();
+    if let Some(index) = message.rfind(" at line ") {
+        message.truncate(index);
+    }
+    FrontmatterError {
+        message,
+        // 開始行の "---" 分をずらす
+        line: e.location().map(|l| l.line() + 1),
+    }
+}
+
+const FIELDS: [&str; 7] = ["name", "description", "allowed-tools", "model", "argument-hint", "license", "metadata"];
+
+// YAMLとして読めない場合はエラー。個々のフィールドの型が合わない場合はそのフィールドだけ読み飛ばす
+fn parse_yaml(yaml: &str) -> Result<(Frontmatter, Vec<FrontmatterError>), FrontmatterError> {
+    if yaml.trim().is_empty() {
+        return Ok((Frontmatter::default(), Vec::new()));
+    }
+
+    let value: serde_yaml::Value = serde_yaml::from_str(yaml).map_err(yaml_error)?;
+    let mapping = match value {
+        serde_yaml::Value::Null => return Ok((Frontmatter::default(), Vec::new())),
+        serde_yaml::Value::Mapping(mapping) => mapping,
+        _ => {
+            return Err(FrontmatterError { message: i18n::t(Key::FrontmatterNotMapping).to_string(), line: Some(2) });
+        }
+    };
+    if let Ok(frontmatter) = serde_yaml::from_value::<Frontmatter>(serde_yaml::Value::Mapping(mapping.clone())) {
+        return Ok((frontmatter, Vec::new()));
+    }
+
+    let mut frontmatter = Frontmatter::default();
+    let mut errors = Vec::new();
+    for key in FIELDS {
+        let Some(value) = mapping.get(key) else {
+            continue;
+        };
+        let mut single = serde_yaml::Mapping::new();
+        single.insert(key.into(), value.clone());
+        match serde_yaml::from_value::<Frontmatter>(serde_yaml::Value::Mapping(single)) {
+            Ok(field) => frontmatter.merge(field),
+            Err(e) => errors.push(FrontmatterError {
+                message: format!("{}: {}", key, e),
+                line: yaml.lines().position(|line| line.starts_with(&format!("{}:", key))).map(|i| i + 2),
+            }),
+        }
+    }
+    Ok((frontmatter, errors))
+}
+
+impl Frontmatter {
+    // 1フィールドずつ読んだ結果をまとめる
+    fn merge(&mut self, other: Frontmatter) {
+        self.name = self.name.take().or(other.name);
+        self.description = self.description.take().or(other.description);
+        self.allowed_tools = self.allowed_tools.take().or(other.allowed_tools);
+        self.model = self.model.take().or(other.model);
+        self.argument_hint = self.argument_hint.take().or(other.argument_hint);
+        self.license = self.license.take().or(other.license);
+        self.metadata = self.metadata.take().or(other.metadata);
+    }
+}
+
+fn first_body_line(body: &str) -> Option<String> {
+    body.lines()
+        .map(|line| line.trim())
+        .find(|line| !line.is_empty() && !line.starts_with('#') && !line.starts_with("---"))
+        .map(|line| line.chars().take(100).collect())
+}
+
+pub fn summarize(content: &str) -> Summary {
+    match parse(content) {
+        Ok(document) => {
+            let description = document
+                .frontmatter
+                .as_ref()
+                .and_then(|f| f.description.as_deref())
+                .map(|d| d.trim().to_string())
+                .filter(|d| !d.is_empty())
+                .or_else(|| first_body_line(document.body))
+                .unwrap_or_else(|| i18n::t(Key::NoDescription).to_string());
+
+            let error = document.field_errors.first().map(|e| e.to_string());
+            Summary { frontmatter: document.frontmatter, description, error }
+        }
+        Err(e) => Summary {
+            frontmatter: None,
+            description: i18n::t(Key::NoDescription).to_string(),
+            error: Some(e.to_string()),
+        },
+    }
+}
+
+// 新規スキル用のfrontmatterを生成する（値のクォートはserde_yamlに任せる）
+pub fn render(name: &str, description: &str) -> Result<String, String> {
+    #[derive(Serialize)]
+    struct Fields<'a> {
+        name: &'a str,
+        description: &'a str,
+    }
+
+    let yaml = serde_yaml::to_string(&Fields { name, description }).map_err(|e| e.to_string())?;
+    Ok(format!("---\n{}---\n", yaml))
+}
+
+// frontmatterの "name:" 行だけを書き換える（他の行の書式はそのまま残す）
+pub fn set_name(content: &str, name: &str) -> Option<String> {
+    let value = serde_yaml::to_string(name).ok()?;
+    let mut lines = content.split_inclusive('\n');
+    let first = lines.next().filter(|line| is_delimiter(line))?;
+
+    let mut result = String::from(first);
+    let mut replaced = false;
+    let mut closed = false;
+    // 置き換えた name の続きの行（"name: >" などの複数行の値）。空行は続きかどうか次の行で決める
+    let mut in_old_value = false;
+    let mut blank_lines = String::new();
+    for line in lines.by_ref() {
+        if in_old_value {
+            if line.trim().is_empty() {
+                blank_lines.push_str(line);
+                continue;
+            }
+            if line.starts_with([' ', '\t']) {
+                blank_lines.clear();
+                continue;
+            }
+            in_old_value = false;
+            result.push_str(&blank_lines);
+        }
+        if is_closing_delimiter(line) {
+            result.push_str(line);
+            closed = true;
+            break;
+        }
+        if !replaced && line.starts_with("name:") {
+            let ending = if line.ends_with("\r\n") { "\r\n" } else if line.ends_with('\n') { "\n" } else { "" };
+            result.push_str("name: ");
+            result.push_str(value.trim_end());
+            result.push_str(ending);
+            replaced = true;
+            in_old_value = true;
+        } else {
+            result.push_str(line);
+        }
+    }
+
+    if !replaced || !closed {
+        return None;
+    }
+    result.extend(lines);
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_folded_and_literal_descriptions() {
+        let folded = "---\nname: a\ndescription: >\n  first line\n  second line\n---\nbody\n";
+        assert_eq!(summarize(folded).description, "first line second line");
+
+        let literal = "---\nname: a\ndescription: |\n  first line\n  second line\n---\nbody\n";
+        assert_eq!(summarize(literal).description, "first line\nsecond line");
+    }
+
+    #[test]
+    fn reads_escaped_quotes() {
+        let content = "---\ndescription: \"Say \\\"hi\\\"\"\nname: 'it''s'\n---\n";
+        let summary = summarize(content);
+        assert_eq!(summary.description, "Say \"hi\"");
+        assert_eq!(summary.frontmatter.unwrap().name.as_deref(), Some("it's"));
+    }
+
+    #[test]
+    fn ignores_description_in_body() {
+        let content = "---\nname: a\n---\ndescription: not this one\n";
+        let document = parse(content).unwrap();
+        assert_eq!(document.frontmatter.unwrap().description, None);
+        assert_eq!(document.body, "description: not this one\n");
+        assert_eq!(document.body_line, 4);
+    }
+
+    #[test]
+    fn accepts_list_style_argument_hint() {
+        let content = "---\ndescription: Commit changes\nargument-hint: [message]\nallowed-tools: Bash(git:*), Read\n---\n";
+        let summary = summarize(content);
+        assert_eq!(summary.description, "Commit changes");
+        assert_eq!(summary.error, None);
+        let frontmatter = summary.frontmatter.unwrap();
+        assert_eq!(frontmatter.argument_hint.as_deref(), Some("[message]"));
+        assert_eq!(frontmatter.allowed_tools, Some(vec!["Bash(git:*)".to_string(), "Read".to_string()]));
+    }
+
+    #[test]
+    fn keeps_description_when_other_field_has_wrong_type() {
+        let content = "---\ndescription: Still readable\nmodel:\n  nested: value\n---\n";
+        let summary = summarize(content);
+        assert_eq!(summary.description, "Still readable");
+        assert!(summary.error.is_some());
+        assert_eq!(summary.frontmatter.unwrap().model, None);
+
+        let error = &parse(content).unwrap().field_errors[0];
+        assert_eq!(error.line, Some(3));
+        assert!(error.message.starts_with("model:"));
+    }
+
+    #[test]
+    fn reports_unclosed_frontmatter() {
+        let summary = summarize("---\ndescription: x\n");
+        // 言語は他のテストが読み込む設定で切り替わることがあるので、どちらかであればよい
+        let no_description = [i18n::Locale::Ja, i18n::Locale::En].map(|l| i18n::text(l, Key::NoDescription));
+        assert!(no_description.contains(&summary.description.as_str()));
+        assert!(summary.error.is_some());
+    }
+
+    #[test]
+    fn set_name_replaces_multi_line_value() {
+        let content = "---\nname: >\n  old\n\n  name\ndescription: d\n---\nbody\n";
+        let updated = set_name(content, "new-name").unwrap();
+        assert_eq!(updated, "---\nname: new-name\ndescription: d\n---\nbody\n");
+
+        let content = "---\nname: old\n\ndescription: d\n---\n";
+        assert_eq!(set_name(content, "new").unwrap(), "---\nname: new\n\ndescription: d\n---\n");
+    }
+}
@@ -0,0 +1,249 @@
+// バックエンドが出すメッセージの翻訳（エラー、デフォルトのカテゴリ名、起動用コマンドの本文）
+use serde::{Deserialize, Serialize};
+use std::sync::{OnceLock, RwLock};
+
+use crate::error::Target;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Locale {
+    Ja,
+    En,
+}
+
+impl Locale {
+    // "ja-JP" / "ja_JP.UTF-8" / "en-US" など。日本語以外は英語にする
+    pub fn from_tag(tag: &str) -> Locale {
+        if tag.to_ascii_lowercase().starts_with("ja") {
+            Locale::Ja
+        } else {
+            Locale::En
+        }
+    }
+}
+
+// 設定ファイルで指定された言語（なければOSの言語）
+static CONFIGURED: RwLock<Option<Locale>> = RwLock::new(None);
+
+fn os_locale() -> Locale {
+    static OS: OnceLock<Locale> = OnceLock::new();
+    // 取得できない環境も日本語以外と同じく英語
+    *OS.get_or_init(|| sys_locale::get_locale().map(|tag| Locale::from_tag(&tag)).unwrap_or(Locale::En))
+}
+
+pub fn set_locale(locale: Option<Locale>) {
+    if let Ok(mut configured) = CONFIGURED.write() {
+        *configured = locale;
+    }
+}
+
+pub fn current() -> Locale {
+    CONFIGURED.read().ok().and_then(|c| *c).unwrap_or_else(os_locale)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Key {
+    DefaultCategory,
+    LauncherDescription,
+    LauncherHeading,
+    LauncherManual,
+    LauncherFooter,
+    NotInProject,
+    NoAgentDir,
+    AgentDirNotFound,
+    PathNotFound,
+    NotFound,
+    AlreadyExists,
+    EnabledAndDisabled,
+    ModifiedOnDisk,
+    ParseWithPath,
+    Parse,
+    Backup,
+    PermissionDenied,
+    InvalidSkillName,
+    EmptyValue,
+    EmptyProfileName,
+    InvalidValue,
+    ArchiveUnsafePath,
+    ArchiveSymlink,
+    ArchiveMultipleSkills,
+    ArchiveTooLarge,
+    ConfigNotObject,
+    ConfigNoCategories,
+    ConfigInvalidVersion,
+    ConfigNewerVersion,
+    AtLine,
+    FrontmatterNotClosed,
+    FrontmatterNotMapping,
+    NoDescription,
+    SkillFileName,
+    FrontmatterMissing,
+    NameMissing,
+    NameTooLong,
+    NameFormat,
+    NameMismatch,
+    DescriptionMissing,
+    DescriptionTooLong,
+    MissingReference,
+}
+
+pub fn text(locale: Locale, key: Key) -> &'static str {
+    match locale {
+        Locale::Ja => match key {
+            Key::DefaultCategory => "未分類",
+            Key::LauncherDescription => "Skills Managerアプリを起動",
+            Key::LauncherHeading => "Skills Managerアプリケーションを起動:",
+            Key::LauncherManual => "Skills Managerアプリケーションを起動してください。",
+            Key::LauncherFooter => "起動のみ行う。それ以外の操作は不要。終了する。",
+            Key::NotInProject => "プロジェクトが開かれていません",
+            Key::NoAgentDir => "{path} に .claude/ または .codex/ がありません",
+            Key::AgentDirNotFound => "{name}ディレクトリが存在しません",
+            Key::PathNotFound => "{name} が見つかりません",
+            Key::NotFound => "{target}「{name}」が見つかりません",
+            Key::AlreadyExists => "同名の{target}「{name}」が既に存在します",
+            Key::EnabledAndDisabled => "{target}「{name}」が有効・無効の両方にあります",
+            Key::ModifiedOnDisk => "{name} は他のアプリで変更されています",
+            Key::ParseWithPath => "{path} を読み込めません（{message}）",
+            Key::Parse => "{target}を読み込めません（{message}）",
+            Key::Backup => "。バックアップ: {path}",
+            Key::PermissionDenied => "{path} にはアクセスできません",
+            Key::InvalidSkillName => "スキル名は英小文字・数字・ハイフンのみ（64文字以内）で指定してください",
+            Key::EmptyValue => "{target}を入力してください",
+            Key::EmptyProfileName => "プロファイル名を入力してください",
+            Key::InvalidValue => "{target}「{value}」は使えません",
+            Key::ArchiveUnsafePath => "不正なパスが含まれています: {name}",
+            Key::ArchiveSymlink => "シンボリックリンクが含まれています: {name}",
+            Key::ArchiveMultipleSkills => "複数のスキルが含まれています",
+            Key::ArchiveTooLarge => "展開後のサイズが上限（{max}MB）を超えています",
+            Key::ConfigNotObject => "設定がJSONのオブジェクトではありません",
+            Key::ConfigNoCategories => "設定に 'categories' がありません",
+            Key::ConfigInvalidVersion => "設定のバージョンが不正です",
+            Key::ConfigNewerVersion => "設定のバージョン {version} はこのアプリが対応するもの（{supported}）より新しいです",
+            Key::AtLine => "{line}行目: {message}",
+            Key::FrontmatterNotClosed => "frontmatterが '---' で閉じられていません",
+            Key::FrontmatterNotMapping => "frontmatterは「キー: 値」の形式で書いてください",
+            Key::NoDescription => "説明なし",
+            Key::SkillFileName => "スキルのファイル名は SKILL.md にしてください",
+            Key::FrontmatterMissing => "SKILL.md はYAMLのfrontmatterで始めてください",
+            Key::NameMissing => "frontmatterに 'name' がありません",
+            Key::NameTooLong => "'name' は{max}文字以内にしてください",
+            Key::NameFormat => "'name' には英小文字・数字・単独のハイフンのみ使えます",
+            Key::NameMismatch => "'name' が '{name}' ですが、フォルダ名は '{dir}' です",
+            Key::DescriptionMissing => "frontmatterに 'description' がありません",
+            Key::DescriptionTooLong => "'description' は{max}文字以内にしてください",
+            Key::MissingReference => "参照しているファイル '{path}' がありません",
+        },
+        Locale::En => match key {
+            Key::DefaultCategory => "Uncategorized",
+            Key::LauncherDescription => "Launch the Skills Manager app",
+            Key::LauncherHeading => "Launch the Skills Manager application:",
+            Key::LauncherManual => "Please launch the Skills Manager application.",
+            Key::LauncherFooter => "Only launch it. Do nothing else. Then finish.",
+            Key::NotInProject => "No project is open",
+            Key::NoAgentDir => "{path} has no .claude/ or .codex/ directory",
+            Key::AgentDirNotFound => "The {name} directory does not exist",
+            Key::PathNotFound => "{name} was not found",
+            Key::NotFound => "The {target} \"{name}\" was not found",
+            Key::AlreadyExists => "A {target} named \"{name}\" already exists",
+            Key::EnabledAndDisabled => "The {target} \"{name}\" exists both enabled and disabled",
+            Key::ModifiedOnDisk => "{name} was modified by another application",
+            Key::ParseWithPath => "Could not read {path} ({message})",
+            Key::Parse => "Could not read the {target} ({message})",
+            Key::Backup => ". Backup: {path}",
+            Key::PermissionDenied => "Access to {path} is not allowed",
+            Key::InvalidSkillName => {
+                "Skill names may only contain lowercase letters, digits and hyphens (up to 64 characters)"
+            }
+            Key::EmptyValue => "Please enter a {target}",
+            Key::EmptyProfileName => "Please enter a profile name",
+            Key::InvalidValue => "\"{value}\" is not a valid {target}",
+            Key::ArchiveUnsafePath => "contains an unsafe path: {name}",
+            Key::ArchiveSymlink => "contains a symbolic link: {name}",
+            Key::ArchiveMultipleSkills => "contains more than one skill",
+            Key::ArchiveTooLarge => "exceeds the size limit ({max} MB) when extracted",
+            Key::ConfigNotObject => "config must be a JSON object",
+            Key::ConfigNoCategories => "config has no 'categories'",
+            Key::ConfigInvalidVersion => "invalid config version",
+            Key::ConfigNewerVersion => "config version {version} is newer than this app supports ({supported})",
+            Key::AtLine => "line {line}: {message}",
+            Key::FrontmatterNotClosed => "frontmatter is not closed with '---'",
+            Key::FrontmatterNotMapping => "frontmatter must be a mapping",
+            Key::NoDescription => "No description",
+            Key::SkillFileName => "skill file should be named exactly SKILL.md",
+            Key::FrontmatterMissing => "SKILL.md must start with a YAML frontmatter block",
+            Key::NameMissing => "frontmatter must define 'name'",
+            Key::NameTooLong => "'name' must be at most {max} characters",
+            Key::NameFormat => "'name' must use lowercase letters, digits and single hyphens",
+            Key::NameMismatch => "'name' is '{name}' but the directory is '{dir}'",
+            Key::DescriptionMissing => "frontmatter must define 'description'",
+            Key::DescriptionTooLong => "'description' must be at most {max} characters",
+            Key::MissingReference => "referenced file '{path}' does not exist",
+        },
+    }
+}
+
+pub fn target_label(locale: Locale, target: Target) -> &'static str {
+    match locale {
+        Locale::Ja => match target {
+            Target::Skill => "スキル",
+            Target::Command => "コマンド",
+            Target::Profile => "プロファイル",
+            Target::Config => "設定ファイル",
+            Target::File => "ファイル",
+            Target::Directory => "フォルダ",
+            Target::Archive => "アーカイブ",
+            Target::AgentDir => "ディレクトリ",
+            Target::Agent => "エージェント",
+            Target::TrashEntry => "ゴミ箱の項目",
+            Target::App => "アプリ",
+            Target::Description => "description",
+            Target::Category => "カテゴリ",
+            Target::Resolution => "解決方法",
+        },
+        Locale::En => match target {
+            Target::Skill => "skill",
+            Target::Command => "command",
+            Target::Profile => "profile",
+            Target::Config => "config file",
+            Target::File => "file",
+            Target::Directory => "folder",
+            Target::Archive => "archive",
+            Target::AgentDir => "directory",
+            Target::Agent => "agent",
+            Target::TrashEntry => "trash entry",
+            Target::App => "app",
+            Target::Description => "description",
+            Target::Category => "category",
+            Target::Resolution => "resolution",
+        },
+    }
+}
+
+// "{name}" などのプレースホルダーを置き換える
+pub fn format(locale: Locale, key: Key, args: &[(&str, &str)]) -> String {
+    args.iter()
+        .fold(text(locale, key).to_string(), |s, (name, value)| s.replace(&format!("{{{}}}", name), value))
+}
+
+// 現在の言語でのテキスト
+pub fn t(key: Key) -> &'static str {
+    text(current(), key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn picks_locale_from_tag_and_fills_placeholders() {
+        assert_eq!(Locale::from_tag("ja-JP"), Locale::Ja);
+        assert_eq!(Locale::from_tag("ja_JP.UTF-8"), Locale::Ja);
+        assert_eq!(Locale::from_tag("en-US"), Locale::En);
+        assert_eq!(Locale::from_tag("fr-FR"), Locale::En);
+
+        let args = [("target", target_label(Locale::En, Target::Profile)), ("name", "docs")];
+        assert_eq!(format(Locale::En, Key::NotFound, &args), "The profile \"docs\" was not found");
+        let args = [("target", target_label(Locale::Ja, Target::Profile)), ("name", "docs")];
+        assert_eq!(format(Locale::Ja, Key::NotFound, &args), "プロファイル「docs」が見つかりません");
+    }
+}
@@ -0,0 +1,74 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::atomic;
+
+// 途中で失敗したときに元に戻すための操作記録
+enum Undo {
+    Rename { from: PathBuf, to: PathBuf },
+    Restore { path: PathBuf, content: Option<Vec<u8>> },
+    CreateDir { path: PathBuf },
+}
+
+#[derive(Default)]
+pub struct Journal {
+    undo: Vec<Undo>,
+}
+
+impl Journal {
+    // fがErrを返したら、それまでの操作をすべて戻す
+    pub fn run<T, E>(f: impl FnOnce(&mut Journal) -> Result<T, E>) -> Result<T, E> {
+        let mut journal = Journal::default();
+        let result = f(&mut journal);
+        if result.is_err() {
+            journal.rollback();
+        }
+        result
+    }
+
+    pub fn rename(&mut self, from: &Path, to: &Path) -> io::Result<()> {
+        fs::rename(from, to)?;
+        self.undo.push(Undo::Rename { from: from.to_path_buf(), to: to.to_path_buf() });
+        Ok(())
+    }
+
+    pub fn write(&mut self, path: &Path, content: impl AsRef<[u8]>) -> io::Result<()> {
+        let previous = match fs::read(path) {
+            Ok(bytes) => Some(bytes),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+            Err(e) => return Err(e),
+        };
+        atomic::write(path, content)?;
+        self.undo.push(Undo::Restore { path: path.to_path_buf(), content: previous });
+        Ok(())
+    }
+
+    // 新しくフォルダを作る（戻すときは中に作ったものごと消す）
+    pub fn create_dir(&mut self, path: &Path) -> io::Result<()> {
+        fs::create_dir(path)?;
+        self.undo.push(Undo::CreateDir { path: path.to_path_buf() });
+        Ok(())
+    }
+
+    // 記録した操作を逆順に戻す（戻せなかったものはログに残す）
+    fn rollback(mut self) {
+        while let Some(undo) = self.undo.pop() {
+            let result = match &undo {
+                Undo::Rename { from, to } => fs::rename(to, from),
+                Undo::Restore { path, content: Some(content) } => atomic::write(path, content),
+                Undo::Restore { path, content: None } => fs::remove_file(path),
+                Undo::CreateDir { path } => fs::remove_dir_all(path),
+            };
+            if let Err(e) = result {
+                match undo {
+                    Undo::Rename { from, to } => {
+                        log::error!("Failed to undo move {:?} -> {:?}: {}", from, to, e)
+                    }
+                    Undo::Restore { path, .. } => log::error!("Failed to restore {:?}: {}", path, e),
+                    Undo::CreateDir { path } => log::error!("Failed to remove {:?}: {}", path, e),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use serde_json::{Map, Value};
+
+use crate::i18n::{self, Key};
+
+// skillsmanager-config.json のレイアウトのバージョン
+// version のない古いファイルは 0 として扱う
+pub const CURRENT_VERSION: u64 = 3;
+
+type Migration = fn(&mut Map<String, Value>);
+
+// MIGRATIONS[n] が version n → n+1 の変換
+const MIGRATIONS: [Migration; CURRENT_VERSION as usize] = [add_category_order, add_command_categories, add_profiles];
+
+// 0 → 1: categoryOrder が追加された（それまではcategoriesのキー順で表示）
+fn add_category_order(config: &mut Map<String, Value>) {
+    let has_order = config
+        .get("categoryOrder")
+        .and_then(Value::as_array)
+        .map(|order| !order.is_empty())
+        .unwrap_or(false);
+    if has_order {
+        return;
+    }
+
+    let order: Vec<Value> = config
+        .get("categories")
+        .and_then(Value::as_object)
+        .map(|categories| categories.keys().cloned().map(Value::String).collect())
+        .unwrap_or_default();
+    config.insert("categoryOrder".to_string(), Value::Array(order));
+}
+
+// 1 → 2: スラッシュコマンドの読み込み設定とカテゴリ分けが追加された
+fn add_command_categories(config: &mut Map<String, Value>) {
+    if !config.get("commandCategories").map(Value::is_object).unwrap_or(false) {
+        config.insert("commandCategories".to_string(), Value::Object(Map::new()));
+    }
+    if !config.get("loadSlashCommands").map(Value::is_boolean).unwrap_or(false) {
+        config.insert("loadSlashCommands".to_string(), Value::Bool(true));
+    }
+}
+
+// 2 → 3: 有効にするスキル・コマンドの組み合わせ（プロファイル）が追加された
+fn add_profiles(config: &mut Map<String, Value>) {
+    if !config.get("profiles").map(Value::is_object).unwrap_or(false) {
+        config.insert("profiles".to_string(), Value::Object(Map::new()));
+    }
+}
+
+// 読み込んだ設定を現在のバージョンまで1段ずつ変換し、元のバージョンを返す
+pub fn migrate(config: &mut Value) -> Result<u64, String> {
+    let locale = i18n::current();
+    let object = config.as_object_mut().ok_or(i18n::text(locale, Key::ConfigNotObject))?;
+    if !object.get("categories").map(Value::is_object).unwrap_or(false) {
+        return Err(i18n::text(locale, Key::ConfigNoCategories).to_string());
+    }
+
+    let from = match object.get("version") {
+        None => 0,
+        Some(version) => version.as_u64().ok_or(i18n::text(locale, Key::ConfigInvalidVersion))?,
+    };
+    if from > CURRENT_VERSION {
+        let (version, supported) = (from.to_string(), CURRENT_VERSION.to_string());
+        return Err(i18n::format(locale, Key::ConfigNewerVersion, &[("version", &version), ("supported", &supported)]));
+    }
+
+    for migration in &MIGRATIONS[from as usize..] {
+        migration(object);
+    }
+    object.insert("version".to_string(), Value::from(CURRENT_VERSION));
+
+    Ok(from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Config;
+
+    fn fixture(name: &str) -> Value {
+        let path = format!("{}/tests/fixtures/config/{}", env!("CARGO_MANIFEST_DIR"), name);
+        let content = std::fs::read_to_string(&path).unwrap();
+        serde_json::from_str(&content).unwrap()
+    }
+
+    fn migrate_fixture(name: &str) -> (Config, u64) {
+        let original = fixture(name);
+        let mut value = original.clone();
+        let from = migrate(&mut value).unwrap();
+        let config: Config = serde_json::from_value(value).unwrap();
+
+        // どのバージョンからでもカテゴリとその中身・順序は変わらない
+        let original_categories = original["categories"].as_object().unwrap();
+        assert_eq!(
+            config.categories.keys().collect::<Vec<_>>(),
+            original_categories.keys().collect::<Vec<_>>()
+        );
+        for (name, skills) in original_categories {
+            let skills: Vec<String> = serde_json::from_value(skills.clone()).unwrap();
+            assert_eq!(config.categories[name], skills);
+        }
+        assert_eq!(config.version, CURRENT_VERSION);
+
+        (config, from)
+    }
+
+    #[test]
+    fn migrates_categories_only_layout() {
+        let (config, from) = migrate_fixture("legacy-categories-only.json");
+        assert_eq!(from, 0);
+        assert_eq!(config.category_order, vec!["未分類", "開発", "ドキュメント"]);
+        assert!(config.load_slash_commands);
+        assert!(config.command_categories.is_empty());
+        assert!(config.profiles.is_empty());
+    }
+
+    #[test]
+    fn keeps_existing_category_order() {
+        let (config, from) = migrate_fixture("legacy-category-order.json");
+        assert_eq!(from, 0);
+        assert_eq!(config.category_order, vec!["開発", "ドキュメント", "未分類"]);
+        assert!(config.load_slash_commands);
+    }
+
+    #[test]
+    fn keeps_command_categories_and_slash_command_setting() {
+        let (config, from) = migrate_fixture("legacy-command-categories.json");
+        assert_eq!(from, 0);
+        assert_eq!(config.category_order, vec!["開発", "未分類"]);
+        assert!(!config.load_slash_commands);
+        assert_eq!(config.command_categories["未分類"], vec!["skillsmanager"]);
+        assert_eq!(config.command_categories["開発"], vec!["commit", "review-pr"]);
+    }
+
+    #[test]
+    fn leaves_current_version_untouched() {
+        let original = fixture("v3-current.json");
+        let mut value = original.clone();
+        assert_eq!(migrate(&mut value).unwrap(), CURRENT_VERSION);
+        assert_eq!(value, original);
+    }
+
+    #[test]
+    fn adds_profiles_to_v2_config() {
+        let (config, from) = migrate_fixture("v2-command-categories.json");
+        assert_eq!(from, 2);
+        assert_eq!(config.command_categories["開発"], vec!["commit"]);
+        assert!(config.profiles.is_empty());
+    }
+
+    #[test]
+    fn migrating_twice_is_a_no_op() {
+        let mut once = fixture("legacy-categories-only.json");
+        migrate(&mut once).unwrap();
+        let mut twice = once.clone();
+        migrate(&mut twice).unwrap();
+        assert_eq!(once, twice);
+    }
+
+    #[test]
+    fn rejects_newer_versions() {
+        let mut value = fixture("future-version.json");
+        assert!(migrate(&mut value).is_err());
+    }
+
+    #[test]
+    fn rejects_config_without_categories() {
+        let mut value = serde_json::json!({ "categoryOrder": ["未分類"] });
+        assert!(migrate(&mut value).is_err());
+    }
+}
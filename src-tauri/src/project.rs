@@ -0,0 +1,1684 @@
+// UIに依存しないプロジェクト操作（Tauriコマンドとskillsmanager-cliの両方から使う）
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use walkdir::WalkDir;
+
+use crate::error::{AppError, ConflictReason, ErrorDetail, Target};
+use crate::frontmatter::{self, Frontmatter};
+use crate::i18n::{self, Locale};
+use crate::journal::Journal;
+use crate::trash::{self, Membership, TrashKind};
+use crate::{archive, atomic, copy_dir_all, migrations, reconcile, validate};
+
+pub use crate::archive::ExportManifest;
+pub use crate::reconcile::ReconcileReport;
+pub use crate::trash::TrashEntry;
+pub use crate::validate::{Severity, SkillDiagnostic};
+
+pub const CONFIG_FILE_NAME: &str = "skillsmanager-config.json";
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SkillFile {
+    pub name: String,
+    pub path: String,
+    pub is_directory: bool,
+}
+
+// ~/.claude（ユーザー全体）かプロジェクトの .claude か
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Scope {
+    User,
+    #[default]
+    Project,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum TransferMode {
+    Copy,
+    Move,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Skill {
+    pub name: String,
+    pub description: String,
+    pub enabled: bool,
+    pub content: String,
+    pub path: String,
+    pub files: Vec<SkillFile>,
+    pub content_token: String,
+    pub frontmatter: Option<Frontmatter>,
+    pub frontmatter_error: Option<String>,
+    pub scope: Scope,
+    // プロジェクトの項目: 同名のユーザーの項目を上書きしている
+    pub shadows: bool,
+    // ユーザーの項目: 同名のプロジェクトの項目に上書きされている
+    pub shadowed: bool,
+    // 有効・無効の両方に同名のものがある（有効な方だけを返す）
+    pub duplicate: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SlashCommand {
+    pub name: String,
+    pub description: String,
+    pub enabled: bool,
+    pub content: String,
+    pub path: String,
+    pub content_token: String,
+    pub frontmatter: Option<Frontmatter>,
+    pub frontmatter_error: Option<String>,
+    pub scope: Scope,
+    // プロジェクトの項目: 同名のユーザーの項目を上書きしている
+    pub shadows: bool,
+    // ユーザーの項目: 同名のプロジェクトの項目に上書きされている
+    pub shadowed: bool,
+    // 有効・無効の両方に同名のものがある（有効な方だけを返す）
+    pub duplicate: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn current_config_version() -> u64 {
+    migrations::CURRENT_VERSION
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    // ファイルから読む場合はmigrationsで補完される。フロントエンドからの保存時は省略可
+    #[serde(default = "current_config_version")]
+    pub version: u64,
+    pub categories: IndexMap<String, Vec<String>>,
+    #[serde(default)]
+    pub category_order: Vec<String>,
+    #[serde(default = "default_true")]
+    pub load_slash_commands: bool,
+    #[serde(default)]
+    pub command_categories: IndexMap<String, Vec<String>>,
+    #[serde(default)]
+    pub profiles: IndexMap<String, Profile>,
+    // バックエンドのメッセージの言語（省略時はOSの言語）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locale: Option<Locale>,
+}
+
+// 作業モードごとに有効にするスキル・コマンドの組み合わせ（それ以外は無効にする）
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Profile {
+    #[serde(default)]
+    pub skills: Vec<String>,
+    #[serde(default)]
+    pub commands: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ItemKind {
+    Skill,
+    Command,
+}
+
+// 有効・無効の両方に同名のものがあるときの解決方法
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum DuplicateResolution {
+    KeepEnabled,
+    KeepDisabled,
+    // 無効側にしかないファイルを有効側にコピーして1つにする（スキルのみ）
+    Merge,
+}
+
+// toggle_many で切り替える項目
+#[derive(Debug, Deserialize, Clone)]
+pub struct ToggleTarget {
+    pub name: String,
+    #[serde(default)]
+    pub scope: Scope,
+}
+
+// toggle_many で実際に移動したもの（既にその状態だったものは含まない）
+#[derive(Debug, Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ToggleChanges {
+    pub skills: Vec<String>,
+    pub commands: Vec<String>,
+}
+
+// apply_profile で実際に切り替わったもの
+#[derive(Debug, Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileChanges {
+    pub enabled_skills: Vec<String>,
+    pub disabled_skills: Vec<String>,
+    pub enabled_commands: Vec<String>,
+    pub disabled_commands: Vec<String>,
+    // プロファイルにあるがプロジェクトに見つからないもの
+    pub missing_skills: Vec<String>,
+    pub missing_commands: Vec<String>,
+}
+
+// ファイル内容のハッシュ（読み込み時と保存時で比較する）
+pub(crate) fn content_token(content: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(content))
+}
+
+// Windowsでファイル名に使えない文字
+const RESERVED_CHARS: [char; 9] = ['<', '>', ':', '"', '/', '\\', '|', '?', '*'];
+
+// スキル・コマンド名が1つの通常のパス要素か（"../x" や "a/b" でフォルダの外を指させない）
+// 隠しファイルになる名前やWindowsで作れない名前も受け付けない
+pub(crate) fn check_name(target: impl Into<Target>, name: &str) -> Result<(), AppError> {
+    let mut components = Path::new(name).components();
+    let valid = match (components.next(), components.next()) {
+        (Some(Component::Normal(part)), None) => part == name,
+        _ => false,
+    };
+    if !valid
+        || name.starts_with('.')
+        || name.ends_with(['.', ' '])
+        || name.chars().any(|c| c.is_control() || RESERVED_CHARS.contains(&c))
+    {
+        return Err(AppError::invalid(target.into(), name));
+    }
+    Ok(())
+}
+
+// 有効・無効のどちらにあるスキルでもパスを返す
+pub(crate) fn find_skill_dir(skills_dir: &Path, disabled_dir: &Path, skill_name: &str) -> Option<PathBuf> {
+    [skills_dir, disabled_dir]
+        .iter()
+        .map(|dir| dir.join(skill_name))
+        .find(|path| path.is_dir())
+}
+
+pub(crate) fn find_command_file(commands_dir: &Path, disabled_dir: &Path, command_name: &str) -> Option<PathBuf> {
+    let filename = format!("{}.md", command_name);
+    [commands_dir, disabled_dir]
+        .iter()
+        .map(|dir| dir.join(&filename))
+        .find(|path| path.is_file())
+}
+
+fn home_dir() -> Option<PathBuf> {
+    #[cfg(windows)]
+    let var = "USERPROFILE";
+    #[cfg(not(windows))]
+    let var = "HOME";
+    std::env::var_os(var).filter(|v| !v.is_empty()).map(PathBuf::from)
+}
+
+fn is_same_dir(a: &Path, b: &Path) -> bool {
+    match (a.canonicalize(), b.canonicalize()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
+}
+
+// プロジェクトとユーザーの両方にある名前（Claude Codeではプロジェクト側が優先される）
+fn names_in_both_scopes<'a>(items: impl Iterator<Item = (&'a str, Scope)>) -> HashSet<String> {
+    let (mut project, mut user) = (HashSet::new(), HashSet::new());
+    for (name, scope) in items {
+        match scope {
+            Scope::Project => project.insert(name),
+            Scope::User => user.insert(name),
+        };
+    }
+    project.intersection(&user).map(|name| name.to_string()).collect()
+}
+
+// "claude" / "codex" に対応するディレクトリ名
+pub fn agent_dir_name(agent: &str) -> Option<&'static str> {
+    match agent {
+        "claude" => Some(".claude"),
+        "codex" => Some(".codex"),
+        _ => None,
+    }
+}
+
+fn sort_files(files: &mut [SkillFile]) {
+    files.sort_by(|a, b| {
+        match (a.is_directory, b.is_directory) {
+            (true, false) => std::cmp::Ordering::Less,
+            (false, true) => std::cmp::Ordering::Greater,
+            _ => a.name.cmp(&b.name),
+        }
+    });
+}
+
+// SKILL.md以外のファイル一覧
+fn get_skill_files(skill_dir: &Path) -> Vec<SkillFile> {
+    let mut files = Vec::new();
+    if let Ok(entries) = fs::read_dir(skill_dir) {
+        for entry in entries.filter_map(|e| e.ok()) {
+            let path = entry.path();
+            let name = entry.file_name().to_string_lossy().to_string();
+            if name.eq_ignore_ascii_case("skill.md") {
+                continue;
+            }
+            files.push(SkillFile {
+                name,
+                path: path.to_string_lossy().to_string(),
+                is_directory: path.is_dir(),
+            });
+        }
+    }
+    sort_files(&mut files);
+    files
+}
+
+#[cfg(feature = "gui")]
+pub(crate) fn list_files(dir: &Path) -> Vec<SkillFile> {
+    let mut files = Vec::new();
+    if let Ok(entries) = fs::read_dir(dir) {
+        for entry in entries.filter_map(|e| e.ok()) {
+            let path = entry.path();
+            let name = entry.file_name().to_string_lossy().to_string();
+            files.push(SkillFile {
+                name,
+                path: path.to_string_lossy().to_string(),
+                is_directory: path.is_dir(),
+            });
+        }
+    }
+    sort_files(&mut files);
+    files
+}
+
+fn load_skills_from_dir(dir: &Path, enabled: bool, scope: Scope, skills: &mut Vec<Skill>) {
+    if !dir.exists() {
+        return;
+    }
+    for entry in WalkDir::new(dir)
+        .max_depth(2)
+        .into_iter()
+        .filter_map(|e| e.ok())
+    {
+        if entry.file_name().to_string_lossy().eq_ignore_ascii_case("skill.md") {
+            if let Some(skill_dir) = entry.path().parent() {
+                let skill_name = skill_dir
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .unwrap_or("unknown")
+                    .to_string();
+
+                let content = fs::read_to_string(entry.path()).unwrap_or_default();
+                let summary = frontmatter::summarize(&content);
+                let token = content_token(content.as_bytes());
+                let files = get_skill_files(skill_dir);
+
+                skills.push(Skill {
+                    name: skill_name,
+                    description: summary.description,
+                    enabled,
+                    content,
+                    path: entry.path().to_string_lossy().to_string(),
+                    files,
+                    content_token: token,
+                    frontmatter: summary.frontmatter,
+                    frontmatter_error: summary.error,
+                    scope,
+                    shadows: false,
+                    shadowed: false,
+                    duplicate: false,
+                });
+            }
+        }
+    }
+}
+
+fn load_commands_from_dir(dir: &Path, enabled: bool, scope: Scope, commands: &mut Vec<SlashCommand>) {
+    if !dir.exists() {
+        return;
+    }
+    if let Ok(entries) = fs::read_dir(dir) {
+        for entry in entries.filter_map(|e| e.ok()) {
+            let path = entry.path();
+            // .mdファイルのみ、サブディレクトリは無視
+            if path.is_file() {
+                if let Some(ext) = path.extension() {
+                    if ext.eq_ignore_ascii_case("md") {
+                        let name = path.file_stem()
+                            .and_then(|s| s.to_str())
+                            .unwrap_or("unknown")
+                            .to_string();
+
+                        let content = fs::read_to_string(&path).unwrap_or_default();
+                        let summary = frontmatter::summarize(&content);
+                        let token = content_token(content.as_bytes());
+
+                        commands.push(SlashCommand {
+                            name,
+                            description: summary.description,
+                            enabled,
+                            content,
+                            path: path.to_string_lossy().to_string(),
+                            content_token: token,
+                            frontmatter: summary.frontmatter,
+                            frontmatter_error: summary.error,
+                            scope,
+                            shadows: false,
+                            shadowed: false,
+                            duplicate: false,
+                        });
+                    }
+                }
+            }
+        }
+    }
+}
+
+// 有効・無効の両方にある名前は有効な方（先に読み込んだ方）だけを残して duplicate を立てる
+fn drop_duplicates<T>(items: Vec<T>, key: impl Fn(&T) -> (&str, Scope), mark: impl Fn(&mut T)) -> Vec<T> {
+    let mut kept: Vec<T> = Vec::new();
+    for item in items {
+        match kept.iter_mut().find(|k| key(k) == key(&item)) {
+            Some(existing) => mark(existing),
+            None => kept.push(item),
+        }
+    }
+    kept
+}
+
+// dst にないファイルだけを src からコピーする（同名のファイルは dst を優先）
+fn merge_missing(src: &Path, dst: &Path) -> std::io::Result<()> {
+    fs::create_dir_all(dst)?;
+    for entry in fs::read_dir(src)? {
+        let entry = entry?;
+        let src_path = entry.path();
+        let dst_path = dst.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            merge_missing(&src_path, &dst_path)?;
+        } else if !dst_path.exists() {
+            fs::copy(&src_path, &dst_path)?;
+        }
+    }
+    Ok(())
+}
+
+// 有効・無効の両方に同名のものがあって切り替えられない（resolve_duplicate で解決する）
+fn enabled_and_disabled(
+    kind: ItemKind,
+    name: &str,
+    enabled_dir: &Path,
+    disabled_dir: &Path,
+    file_name: &str,
+) -> AppError {
+    let resolutions = match kind {
+        ItemKind::Skill => vec![
+            DuplicateResolution::KeepEnabled,
+            DuplicateResolution::KeepDisabled,
+            DuplicateResolution::Merge,
+        ],
+        ItemKind::Command => vec![DuplicateResolution::KeepEnabled, DuplicateResolution::KeepDisabled],
+    };
+    AppError::conflict(
+        kind,
+        name,
+        ConflictReason::EnabledAndDisabled {
+            enabled_path: enabled_dir.join(file_name).to_string_lossy().to_string(),
+            disabled_path: disabled_dir.join(file_name).to_string_lossy().to_string(),
+            resolutions,
+        },
+    )
+}
+
+// 有効/無効を切り替えるときの移動元と移動先
+fn toggle_paths(enabled_dir: &Path, disabled_dir: &Path, file_name: &str, enabled: bool) -> (PathBuf, PathBuf) {
+    if enabled {
+        (disabled_dir.join(file_name), enabled_dir.join(file_name))
+    } else {
+        (enabled_dir.join(file_name), disabled_dir.join(file_name))
+    }
+}
+
+// まとめて移動する。移動先が既にあれば何も動かさずにエラー、途中で失敗したらそれまでの移動を戻す
+fn move_all(moves: &[(PathBuf, PathBuf)]) -> Result<(), AppError> {
+    if let Some((_, dst)) = moves.iter().find(|(_, dst)| dst.exists()) {
+        let name = dst.file_name().unwrap_or_default().to_string_lossy().to_string();
+        return Err(AppError::already_exists(Target::File, name, dst));
+    }
+    for parent in moves.iter().filter_map(|(_, dst)| dst.parent()) {
+        if !parent.exists() {
+            fs::create_dir_all(parent).map_err(|e| AppError::io(parent, e))?;
+        }
+    }
+    Journal::run(|journal| {
+        for (src, dst) in moves {
+            journal.rename(src, dst).map_err(|e| AppError::io(src, e))?;
+        }
+        Ok(())
+    })
+}
+
+// 読み込めない設定ファイルを <name>.broken-<日時> にコピーする
+// 読み込むたびに増えないよう、最新のバックアップと同じ内容ならそれを返す
+fn backup_broken_config(path: &Path, content: &str) -> Result<PathBuf, AppError> {
+    let prefix = format!("{}.broken-", CONFIG_FILE_NAME);
+    let latest = path
+        .parent()
+        .and_then(|dir| fs::read_dir(dir).ok())
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_name().to_string_lossy().starts_with(&prefix))
+        .map(|entry| entry.path())
+        .max();
+    if let Some(latest) = latest {
+        if fs::read_to_string(&latest).is_ok_and(|backup| backup == content) {
+            return Ok(latest);
+        }
+    }
+
+    let backup = path.with_file_name(format!("{}{}", prefix, chrono::Local::now().format("%Y%m%d-%H%M%S")));
+    fs::copy(path, &backup).map_err(|e| AppError::io(&backup, e))?;
+    Ok(backup)
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SkillConflictInfo {
+    pub exists: bool,
+    pub target_agent: String,
+    pub is_disabled: bool,
+    pub source_modified: Option<String>,
+    pub target_modified: Option<String>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigReconcileReport {
+    pub skills: ReconcileReport,
+    pub commands: ReconcileReport,
+    // fix=true で設定を書き換えたかどうか
+    pub fixed: bool,
+}
+
+fn rename_in_list(names: &mut [String], old_name: &str, new_name: &str) {
+    for name in names.iter_mut() {
+        if name == old_name {
+            *name = new_name.to_string();
+        }
+    }
+}
+
+fn rename_in_categories(categories: &mut IndexMap<String, Vec<String>>, old_name: &str, new_name: &str) {
+    for names in categories.values_mut() {
+        rename_in_list(names, old_name, new_name);
+    }
+}
+
+// name を取り除き、入っていたカテゴリを返す
+fn remove_from_categories(categories: &mut IndexMap<String, Vec<String>>, name: &str) -> Vec<String> {
+    let mut removed = Vec::new();
+    for (category, names) in categories.iter_mut() {
+        if names.iter().any(|n| n == name) {
+            names.retain(|n| n != name);
+            removed.push(category.clone());
+        }
+    }
+    removed
+}
+
+// name を取り除き、入っていたプロファイルを返す
+fn remove_from_profiles(
+    profiles: &mut IndexMap<String, Profile>,
+    list: impl Fn(&mut Profile) -> &mut Vec<String>,
+    name: &str,
+) -> Vec<String> {
+    let mut removed = Vec::new();
+    for (profile_name, profile) in profiles.iter_mut() {
+        let names = list(profile);
+        if names.iter().any(|n| n == name) {
+            names.retain(|n| n != name);
+            removed.push(profile_name.clone());
+        }
+    }
+    removed
+}
+
+fn get_dir_modified_time(path: &Path) -> Option<String> {
+    // ディレクトリ内のファイルの最新更新日時を取得
+    let mut latest: Option<std::time::SystemTime> = None;
+
+    for entry in WalkDir::new(path).into_iter().filter_map(|e| e.ok()) {
+        if let Ok(metadata) = entry.metadata() {
+            if let Ok(modified) = metadata.modified() {
+                latest = Some(match latest {
+                    Some(current) => if modified > current { modified } else { current },
+                    None => modified,
+                });
+            }
+        }
+    }
+
+    latest.map(|time| {
+        let datetime: chrono::DateTime<chrono::Local> = time.into();
+        datetime.format("%Y/%m/%d %H:%M").to_string()
+    })
+}
+
+// JSONを読み込み、現在のバージョンに変換してからConfigにする
+fn parse_config(content: &str) -> Result<(Config, u64), String> {
+    let mut value: serde_json::Value = serde_json::from_str(content).map_err(|e| e.to_string())?;
+    let from_version = migrations::migrate(&mut value)?;
+    let config = serde_json::from_value(value).map_err(|e| e.to_string())?;
+    Ok((config, from_version))
+}
+
+// .claude/ または .codex/ ディレクトリ
+#[derive(Debug, Clone)]
+pub struct Project {
+    base_dir: PathBuf,
+}
+
+impl Project {
+    pub fn new(base_dir: PathBuf) -> Self {
+        Project { base_dir }
+    }
+
+    // プロジェクトのルートか、.claude/・.codex/ そのものを受け付ける
+    pub fn open(path: &Path) -> Result<Self, AppError> {
+        let path = path.canonicalize().map_err(|e| AppError::io(path, e))?;
+        let is_agent_dir = path
+            .file_name()
+            .map(|n| n == ".claude" || n == ".codex")
+            .unwrap_or(false);
+        if is_agent_dir {
+            return Ok(Project::new(path));
+        }
+
+        [".claude", ".codex"]
+            .iter()
+            .map(|name| path.join(name))
+            .find(|dir| dir.is_dir())
+            .map(Project::new)
+            .ok_or_else(|| AppError::no_agent_dir(&path))
+    }
+
+    pub fn base_dir(&self) -> &Path {
+        &self.base_dir
+    }
+
+    pub fn project_root(&self) -> Option<&Path> {
+        self.base_dir.parent()
+    }
+
+    pub fn agent_type(&self) -> String {
+        self.base_dir
+            .file_name()
+            .map(|name| match name.to_string_lossy().as_ref() {
+                ".claude" => "claude".to_string(),
+                ".codex" => "codex".to_string(),
+                _ => "none".to_string(),
+            })
+            .unwrap_or_else(|| "none".to_string())
+    }
+
+    pub fn config_path(&self) -> PathBuf {
+        self.base_dir.join(CONFIG_FILE_NAME)
+    }
+
+    pub fn skills_dir(&self) -> PathBuf {
+        self.base_dir.join("skills")
+    }
+
+    pub fn disabled_skills_dir(&self) -> PathBuf {
+        self.base_dir.join("disabled-skills")
+    }
+
+    pub fn commands_dir(&self) -> PathBuf {
+        self.base_dir.join("commands")
+    }
+
+    pub fn disabled_commands_dir(&self) -> PathBuf {
+        self.base_dir.join("disabled-commands")
+    }
+
+    pub fn trash_dir(&self) -> PathBuf {
+        self.base_dir.join(trash::TRASH_DIR_NAME)
+    }
+
+    // ~/.claude（.codexのプロジェクトなら ~/.codex）。プロジェクト自体がホームの場合はNone
+    pub fn user_dir(&self) -> Option<PathBuf> {
+        let dir = home_dir()?.join(self.base_dir.file_name()?);
+        (!is_same_dir(&dir, &self.base_dir)).then_some(dir)
+    }
+
+    // ~/.claude 自体を開いている（ホームをプロジェクトとして開いた場合など）
+    pub fn is_user_level(&self) -> bool {
+        let dir = self.base_dir.file_name().and_then(|name| Some(home_dir()?.join(name)));
+        dir.is_some_and(|dir| is_same_dir(&dir, &self.base_dir))
+    }
+
+    pub fn scope_dir(&self, scope: Scope) -> Result<PathBuf, AppError> {
+        match scope {
+            Scope::Project => Ok(self.base_dir.clone()),
+            Scope::User => self.user_dir().ok_or_else(|| {
+                let name = self.base_dir.file_name().unwrap_or_default().to_string_lossy();
+                AppError::not_found(Target::Directory, format!("~/{}", name))
+            }),
+        }
+    }
+
+    // ゴミ箱の original_path の基準（プロジェクトのルートか、ホーム）
+    fn scope_root(&self, scope: Scope) -> Option<PathBuf> {
+        match scope {
+            Scope::Project => self.project_root().map(Path::to_path_buf),
+            Scope::User => self.user_dir()?.parent().map(Path::to_path_buf),
+        }
+    }
+
+    // このプロジェクトのゴミ箱に入れる（~/.claude や別エージェントの項目も同じゴミ箱に入る）
+    fn trash_item(&self, path: &Path, kind: TrashKind, membership: Membership) -> Result<TrashEntry, AppError> {
+        let in_user_dir = self.user_dir().is_some_and(|dir| path.starts_with(dir));
+        let scope = if in_user_dir { Scope::User } else { Scope::Project };
+        let root = self.scope_root(scope).ok_or_else(AppError::not_in_project)?;
+        trash::move_to_trash(&self.trash_dir(), &root, scope, path, kind, membership).map_err(|e| AppError::io(path, e))
+    }
+
+    // 読み込む範囲（プロジェクトが先）
+    fn scopes(&self) -> Vec<(Scope, PathBuf)> {
+        let mut scopes = vec![(Scope::Project, self.base_dir.clone())];
+        if let Some(user_dir) = self.user_dir() {
+            scopes.push((Scope::User, user_dir));
+        }
+        scopes
+    }
+
+    pub fn load_skills(&self) -> Result<Vec<Skill>, AppError> {
+        let skills_dir = self.skills_dir();
+
+        // skillsディレクトリがなければ作成
+        if !skills_dir.exists() {
+            fs::create_dir_all(&skills_dir).map_err(|e| AppError::io(&skills_dir, e))?;
+        }
+
+        let mut skills = Vec::new();
+        for (scope, dir) in self.scopes() {
+            load_skills_from_dir(&dir.join("skills"), true, scope, &mut skills);
+            load_skills_from_dir(&dir.join("disabled-skills"), false, scope, &mut skills);
+        }
+        let mut skills = drop_duplicates(skills, |s| (s.name.as_str(), s.scope), |s| s.duplicate = true);
+
+        let shadowing = names_in_both_scopes(skills.iter().map(|s| (s.name.as_str(), s.scope)));
+        for skill in &mut skills {
+            let in_both = shadowing.contains(&skill.name);
+            skill.shadows = in_both && skill.scope == Scope::Project;
+            skill.shadowed = in_both && skill.scope == Scope::User;
+        }
+        skills.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(skills)
+    }
+
+    pub fn load_slash_commands(&self) -> Result<Vec<SlashCommand>, AppError> {
+        let mut commands = Vec::new();
+        for (scope, dir) in self.scopes() {
+            load_commands_from_dir(&dir.join("commands"), true, scope, &mut commands);
+            load_commands_from_dir(&dir.join("disabled-commands"), false, scope, &mut commands);
+        }
+        let mut commands = drop_duplicates(commands, |c| (c.name.as_str(), c.scope), |c| c.duplicate = true);
+
+        let shadowing = names_in_both_scopes(commands.iter().map(|c| (c.name.as_str(), c.scope)));
+        for command in &mut commands {
+            let in_both = shadowing.contains(&command.name);
+            command.shadows = in_both && command.scope == Scope::Project;
+            command.shadowed = in_both && command.scope == Scope::User;
+        }
+        commands.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(commands)
+    }
+
+    pub fn validate_skills(&self) -> Result<Vec<SkillDiagnostic>, AppError> {
+        let skills = self.load_skills()?;
+        Ok(skills.iter().flat_map(validate::validate_skill).collect())
+    }
+
+    pub fn load_config(&self) -> Result<Config, AppError> {
+        let path = self.config_path();
+
+        match fs::read_to_string(&path) {
+            Ok(content) => {
+                return match parse_config(&content) {
+                    Ok((mut config, from_version)) => {
+                        i18n::set_locale(config.locale);
+                        // category_orderが空なら、categoriesのキー順で初期化
+                        if config.category_order.is_empty() {
+                            config.category_order = config.categories.keys().cloned().collect();
+                        }
+                        // 古いレイアウトから変換した場合は元のファイルを残してから書き戻す
+                        if from_version != migrations::CURRENT_VERSION {
+                            let backup = path.with_file_name(format!("{}.v{}.bak", CONFIG_FILE_NAME, from_version));
+                            fs::copy(&path, &backup).map_err(|e| AppError::io(&backup, e))?;
+                            self.save_config(&config)?;
+                        }
+                        Ok(config)
+                    }
+                    Err(e) => {
+                        // 壊れた設定をデフォルトで上書きせず、バックアップを残してエラーを返す
+                        let backup = backup_broken_config(&path, &content)?;
+                        Err(ErrorDetail::Parse {
+                            target: Target::Config,
+                            path: Some(path.to_string_lossy().to_string()),
+                            detail: e,
+                            backup: Some(backup.to_string_lossy().to_string()),
+                        }
+                        .into())
+                    }
+                };
+            }
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                return Err(AppError::io(&path, e));
+            }
+            Err(_) => {}
+        }
+
+        i18n::set_locale(None);
+        let default_category = i18n::t(i18n::Key::DefaultCategory).to_string();
+        let mut categories = IndexMap::new();
+        categories.insert(default_category.clone(), Vec::new());
+        let category_order = vec![default_category];
+        let command_categories = IndexMap::new();
+        let default_config = Config {
+            version: migrations::CURRENT_VERSION,
+            categories,
+            category_order,
+            load_slash_commands: true,
+            command_categories,
+            profiles: IndexMap::new(),
+            locale: None,
+        };
+
+        let _ = self.save_config(&default_config);
+
+        Ok(default_config)
+    }
+
+    // 設定ファイルの locale だけを読んで以降のメッセージに使う（読めなければOSの言語）
+    pub fn apply_locale(&self) {
+        let locale = fs::read_to_string(self.config_path())
+            .ok()
+            .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+            .and_then(|value| serde_json::from_value(value.get("locale")?.clone()).ok());
+        i18n::set_locale(locale);
+    }
+
+    pub fn save_config(&self, config: &Config) -> Result<(), AppError> {
+        i18n::set_locale(config.locale);
+        let path = self.config_path();
+        let json = serde_json::to_string_pretty(config).map_err(|e| AppError::parse(Target::Config, Some(&path), e))?;
+        atomic::write(&path, json).map_err(|e| AppError::io(&path, e))
+    }
+
+    // 有効・無効ディレクトリと、その中でのファイル名
+    fn item_paths(&self, kind: ItemKind, name: &str, scope: Scope) -> Result<(PathBuf, PathBuf, String), AppError> {
+        check_name(kind, name)?;
+        let dir = self.scope_dir(scope)?;
+        Ok(match kind {
+            ItemKind::Skill => (dir.join("skills"), dir.join("disabled-skills"), name.to_string()),
+            ItemKind::Command => (dir.join("commands"), dir.join("disabled-commands"), format!("{}.md", name)),
+        })
+    }
+
+    fn toggle_item(&self, kind: ItemKind, name: &str, enabled: bool, scope: Scope) -> Result<(), AppError> {
+        let (enabled_dir, disabled_dir, file_name) = self.item_paths(kind, name, scope)?;
+        let (src, dst) = toggle_paths(&enabled_dir, &disabled_dir, &file_name, enabled);
+
+        // 移動先に同名のものがあると、環境によって失敗したり中身が混ざったりする
+        if src.exists() && dst.exists() {
+            return Err(enabled_and_disabled(kind, name, &enabled_dir, &disabled_dir, &file_name));
+        }
+        if !src.exists() {
+            // 既に指定の状態になっているなら何もしない
+            if dst.exists() {
+                return Ok(());
+            }
+            return Err(AppError::not_found(kind, name));
+        }
+
+        if let Some(parent) = dst.parent() {
+            fs::create_dir_all(parent).map_err(|e| AppError::io(parent, e))?;
+        }
+        fs::rename(&src, &dst).map_err(|e| AppError::io(&src, e))?;
+        Ok(())
+    }
+
+    pub fn toggle_skill(&self, skill_name: &str, enabled: bool, scope: Scope) -> Result<(), AppError> {
+        self.toggle_item(ItemKind::Skill, skill_name, enabled, scope)
+    }
+
+    pub fn toggle_slash_command(&self, command_name: &str, enabled: bool, scope: Scope) -> Result<(), AppError> {
+        self.toggle_item(ItemKind::Command, command_name, enabled, scope)
+    }
+
+    // 有効・無効の両方にある同名のスキル・コマンドを1つにする。残さなかった方はゴミ箱へ
+    pub fn resolve_duplicate(
+        &self,
+        kind: ItemKind,
+        name: &str,
+        scope: Scope,
+        resolution: DuplicateResolution,
+    ) -> Result<(), AppError> {
+        let (enabled_dir, disabled_dir, file_name) = self.item_paths(kind, name, scope)?;
+        let enabled_path = enabled_dir.join(&file_name);
+        let disabled_path = disabled_dir.join(&file_name);
+        if !enabled_path.exists() || !disabled_path.exists() {
+            return Err(AppError::not_found(kind, name));
+        }
+
+        let trash_kind = if kind == ItemKind::Skill { TrashKind::Skill } else { TrashKind::Command };
+        let discarded = match resolution {
+            DuplicateResolution::KeepEnabled => disabled_path,
+            DuplicateResolution::KeepDisabled => enabled_path,
+            DuplicateResolution::Merge => {
+                if kind != ItemKind::Skill {
+                    return Err(AppError::invalid(Target::Resolution, "merge"));
+                }
+                merge_missing(&disabled_path, &enabled_path).map_err(|e| AppError::io(&disabled_path, e))?;
+                disabled_path
+            }
+        };
+        self.trash_item(&discarded, trash_kind, Membership::default())?;
+        Ok(())
+    }
+
+    // 複数のスキル・コマンドをまとめて有効/無効にする。
+    // 先にすべての移動を決めてから実行し、1つでも失敗したら全部元に戻す
+    pub fn toggle_many(
+        &self,
+        skills: &[ToggleTarget],
+        commands: &[ToggleTarget],
+        enabled: bool,
+    ) -> Result<ToggleChanges, AppError> {
+        let mut changes = ToggleChanges::default();
+        let mut moves: Vec<(PathBuf, PathBuf)> = Vec::new();
+
+        let kinds = [
+            (skills, ItemKind::Skill, &mut changes.skills),
+            (commands, ItemKind::Command, &mut changes.commands),
+        ];
+        for (targets, kind, changed) in kinds {
+            for target in targets {
+                let (enabled_dir, disabled_dir, file_name) = self.item_paths(kind, &target.name, target.scope)?;
+                let (src, dst) = toggle_paths(&enabled_dir, &disabled_dir, &file_name, enabled);
+                if moves.iter().any(|(planned, _)| planned == &src) {
+                    continue;
+                }
+                if src.exists() && dst.exists() {
+                    return Err(enabled_and_disabled(kind, &target.name, &enabled_dir, &disabled_dir, &file_name));
+                }
+                if !src.exists() {
+                    // 既に指定の状態になっているものは何もしない
+                    if dst.exists() {
+                        continue;
+                    }
+                    return Err(AppError::not_found(kind, target.name.as_str()));
+                }
+                moves.push((src, dst));
+                changed.push(target.name.clone());
+            }
+        }
+
+        move_all(&moves)?;
+        Ok(changes)
+    }
+
+    // 現在有効になっているプロジェクトのスキル・コマンドをプロファイルとして保存する
+    pub fn save_profile(&self, name: &str) -> Result<Profile, AppError> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(AppError::invalid(Target::Profile, name));
+        }
+        let mut config = self.load_config()?;
+
+        let skills = self
+            .load_skills()?
+            .into_iter()
+            .filter(|s| s.scope == Scope::Project && s.enabled)
+            .map(|s| s.name)
+            .collect();
+        let commands = if config.load_slash_commands {
+            self.load_slash_commands()?
+                .into_iter()
+                .filter(|c| c.scope == Scope::Project && c.enabled)
+                .map(|c| c.name)
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let profile = Profile { skills, commands };
+        config.profiles.insert(name.to_string(), profile.clone());
+        self.save_config(&config)?;
+        Ok(profile)
+    }
+
+    pub fn delete_profile(&self, name: &str) -> Result<(), AppError> {
+        let mut config = self.load_config()?;
+        if config.profiles.shift_remove(name).is_none() {
+            return Err(AppError::not_found(Target::Profile, name));
+        }
+        self.save_config(&config)
+    }
+
+    // プロファイルにあるスキル・コマンドだけを有効にし、それ以外を無効にする。
+    // 1つでも移動に失敗したら全部元に戻す。ユーザー（~/.claude）の項目は対象外
+    pub fn apply_profile(&self, name: &str) -> Result<ProfileChanges, AppError> {
+        let config = self.load_config()?;
+        let profile = config
+            .profiles
+            .get(name)
+            .ok_or_else(|| AppError::not_found(Target::Profile, name))?;
+
+        let skills: Vec<(String, bool)> = self
+            .load_skills()?
+            .into_iter()
+            .filter(|s| s.scope == Scope::Project)
+            .map(|s| (s.name, s.enabled))
+            .collect();
+        // スラッシュコマンドを読み込まない設定ならコマンドには触らない
+        let commands: Vec<(String, bool)> = if config.load_slash_commands {
+            self.load_slash_commands()?
+                .into_iter()
+                .filter(|c| c.scope == Scope::Project)
+                .map(|c| (c.name, c.enabled))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let mut changes = ProfileChanges::default();
+        let mut moves = Vec::new();
+        // toggle_many と同じく、有効・無効の両方にあるものは動かす前に Conflict にする
+        let mut plan = |kind: ItemKind, name: &str, enabled_dir: PathBuf, disabled_dir: PathBuf, file_name: &str, wanted| {
+            let (src, dst) = toggle_paths(&enabled_dir, &disabled_dir, file_name, wanted);
+            if dst.exists() {
+                return Err(enabled_and_disabled(kind, name, &enabled_dir, &disabled_dir, file_name));
+            }
+            moves.push((src, dst));
+            Ok(())
+        };
+        for (skill_name, enabled) in &skills {
+            let wanted = profile.skills.contains(skill_name);
+            if wanted == *enabled {
+                continue;
+            }
+            plan(ItemKind::Skill, skill_name, self.skills_dir(), self.disabled_skills_dir(), skill_name, wanted)?;
+            if wanted {
+                changes.enabled_skills.push(skill_name.clone());
+            } else {
+                changes.disabled_skills.push(skill_name.clone());
+            }
+        }
+        for (command_name, enabled) in &commands {
+            let wanted = profile.commands.contains(command_name);
+            if wanted == *enabled {
+                continue;
+            }
+            let filename = format!("{}.md", command_name);
+            plan(ItemKind::Command, command_name, self.commands_dir(), self.disabled_commands_dir(), &filename, wanted)?;
+            if wanted {
+                changes.enabled_commands.push(command_name.clone());
+            } else {
+                changes.disabled_commands.push(command_name.clone());
+            }
+        }
+        changes.missing_skills = profile
+            .skills
+            .iter()
+            .filter(|name| !skills.iter().any(|(s, _)| s == *name))
+            .cloned()
+            .collect();
+        if config.load_slash_commands {
+            changes.missing_commands = profile
+                .commands
+                .iter()
+                .filter(|name| !commands.iter().any(|(c, _)| c == *name))
+                .cloned()
+                .collect();
+        }
+
+        move_all(&moves)?;
+
+        Ok(changes)
+    }
+
+    // スキルをプロジェクトとユーザー（~/.claude）の間でコピー・移動する（有効/無効の状態は引き継ぐ）
+    pub fn transfer_skill(
+        &self,
+        skill_name: &str,
+        from: Scope,
+        to: Scope,
+        mode: TransferMode,
+        force: bool,
+    ) -> Result<(), AppError> {
+        check_name(Target::Skill, skill_name)?;
+        if from == to {
+            return Ok(());
+        }
+        let from_dir = self.scope_dir(from)?;
+        let to_dir = self.scope_dir(to)?;
+
+        let src = find_skill_dir(&from_dir.join("skills"), &from_dir.join("disabled-skills"), skill_name)
+            .ok_or_else(|| AppError::not_found(Target::Skill, skill_name))?;
+        let enabled = src.starts_with(from_dir.join("skills"));
+        let dst = to_dir.join(if enabled { "skills" } else { "disabled-skills" }).join(skill_name);
+
+        for existing in [to_dir.join("skills").join(skill_name), to_dir.join("disabled-skills").join(skill_name)] {
+            if existing.exists() {
+                if !force {
+                    return Err(AppError::already_exists(Target::Skill, skill_name, &existing));
+                }
+                self.trash_item(&existing, TrashKind::Skill, Membership::default())?;
+            }
+        }
+
+        if let Some(parent) = dst.parent() {
+            fs::create_dir_all(parent).map_err(|e| AppError::io(parent, e))?;
+        }
+        match mode {
+            TransferMode::Move => trash::move_path(&src, &dst).map_err(|e| AppError::io(&src, e)),
+            TransferMode::Copy => copy_dir_all(&src, &dst).map_err(|e| {
+                let _ = fs::remove_dir_all(&dst);
+                AppError::io(&src, e)
+            }),
+        }
+    }
+
+    // 同じプロジェクトの別エージェント（.claude ↔ .codex）の skills/ にコピーする
+    pub fn copy_skill_to_agent(
+        &self,
+        skill_name: &str,
+        enabled: bool,
+        target_agent: &str,
+        force: bool,
+    ) -> Result<(), AppError> {
+        check_name(Target::Skill, skill_name)?;
+        let target_dir_name = agent_dir_name(target_agent).ok_or_else(|| AppError::invalid(Target::Agent, target_agent))?;
+        // 現在のエージェントへのコピーはできない
+        if target_agent == self.agent_type() {
+            return Err(AppError::invalid(Target::Agent, target_agent));
+        }
+
+        let project_root = self.project_root().ok_or_else(AppError::not_in_project)?;
+        let target_agent_dir = project_root.join(target_dir_name);
+
+        // ターゲットのエージェントディレクトリが存在するかチェック
+        if !target_agent_dir.exists() {
+            return Err(AppError::not_found(Target::AgentDir, target_dir_name));
+        }
+
+        // コピー元のパスを決定（有効/無効に応じて）
+        let src_dir = if enabled {
+            self.skills_dir().join(skill_name)
+        } else {
+            self.disabled_skills_dir().join(skill_name)
+        };
+
+        if !src_dir.exists() {
+            return Err(AppError::not_found(Target::Skill, skill_name));
+        }
+
+        // コピー先のskillsディレクトリを作成（なければ）
+        let target_skills_dir = target_agent_dir.join("skills");
+        if !target_skills_dir.exists() {
+            fs::create_dir_all(&target_skills_dir).map_err(|e| AppError::io(&target_skills_dir, e))?;
+        }
+
+        let target_skill_dir = target_skills_dir.join(skill_name);
+        let target_disabled_dir = target_agent_dir.join("disabled-skills").join(skill_name);
+
+        // 同名フォルダが存在するかチェック（上書き時は元のスキルをゴミ箱へ）
+        if target_skill_dir.exists() {
+            if force {
+                self.trash_item(&target_skill_dir, TrashKind::Skill, Membership::default())?;
+            } else {
+                return Err(AppError::already_exists(Target::Skill, skill_name, &target_skill_dir));
+            }
+        }
+
+        // disabled-skillsにも存在するかチェック
+        if target_disabled_dir.exists() {
+            if force {
+                self.trash_item(&target_disabled_dir, TrashKind::Skill, Membership::default())?;
+            } else {
+                return Err(AppError::already_exists(Target::Skill, skill_name, &target_disabled_dir));
+            }
+        }
+
+        // コピー実行
+        copy_dir_all(&src_dir, &target_skill_dir).map_err(|e| AppError::io(&src_dir, e))?;
+
+        Ok(())
+    }
+
+    pub fn export_skills(
+        &self,
+        skill_names: &[String],
+        output_path: &Path,
+        exporter_version: &str,
+        overwrite: bool,
+    ) -> Result<ExportManifest, AppError> {
+        let skills_dir = self.skills_dir();
+        let disabled_dir = self.disabled_skills_dir();
+
+        let mut sources = Vec::new();
+        for name in skill_names {
+            check_name(Target::Skill, name)?;
+            let dir = find_skill_dir(&skills_dir, &disabled_dir, name)
+                .ok_or_else(|| AppError::not_found(Target::Skill, name.as_str()))?;
+            let content = fs::read_to_string(dir.join("SKILL.md")).unwrap_or_default();
+            sources.push(archive::ExportSource {
+                description: frontmatter::summarize(&content).description,
+                enabled: dir.starts_with(&skills_dir),
+                name: name.clone(),
+                dir,
+            });
+        }
+
+        archive::export(&sources, output_path, exporter_version, overwrite)
+    }
+
+    pub fn create_skill(
+        &self,
+        name: &str,
+        description: &str,
+        category: &str,
+        with_scripts: bool,
+        with_references: bool,
+        with_assets: bool,
+    ) -> Result<(), AppError> {
+        if !validate::is_valid_skill_name(name) {
+            return Err(AppError::invalid(Target::Skill, name));
+        }
+        if description.trim().is_empty() {
+            return Err(AppError::invalid(Target::Description, ""));
+        }
+        let category = category.trim();
+        if category.is_empty() {
+            return Err(AppError::invalid(Target::Category, ""));
+        }
+
+        let skills_dir = self.skills_dir();
+        let skill_dir = skills_dir.join(name);
+        for existing in [&skill_dir, &self.disabled_skills_dir().join(name)] {
+            if existing.exists() {
+                return Err(AppError::already_exists(Target::Skill, name, existing));
+            }
+        }
+
+        let skill_md = format!(
+            "{}\n# {}\n\n## Instructions\n\n",
+            frontmatter::render(name, description.trim()).map_err(|e| AppError::parse(Target::Skill, None, e))?,
+            name
+        );
+
+        // 指定カテゴリに登録（なければカテゴリを追加）
+        let mut config = self.load_config()?;
+        if !config.categories.contains_key(category) {
+            config.category_order.push(category.to_string());
+        }
+        config.categories.entry(category.to_string()).or_default().push(name.to_string());
+        let config_path = self.config_path();
+        let config_json = serde_json::to_string_pretty(&config)
+            .map_err(|e| AppError::parse(Target::Config, Some(&config_path), e))?;
+
+        fs::create_dir_all(&skills_dir).map_err(|e| AppError::io(&skills_dir, e))?;
+        // 途中で失敗したらスキルのフォルダごと消す
+        Journal::run(|journal| {
+            journal.create_dir(&skill_dir).map_err(|e| AppError::io(&skill_dir, e))?;
+            let skill_md_path = skill_dir.join("SKILL.md");
+            atomic::write(&skill_md_path, skill_md).map_err(|e| AppError::io(&skill_md_path, e))?;
+
+            for (create, folder) in [(with_scripts, "scripts"), (with_references, "references"), (with_assets, "assets")] {
+                if create {
+                    let dir = skill_dir.join(folder);
+                    fs::create_dir(&dir).map_err(|e| AppError::io(&dir, e))?;
+                }
+            }
+
+            journal.write(&config_path, config_json).map_err(|e| AppError::io(&config_path, e))
+        })
+    }
+
+    pub fn rename_skill(&self, old_name: &str, new_name: &str) -> Result<(), AppError> {
+        let skills_dir = self.skills_dir();
+        let disabled_dir = self.disabled_skills_dir();
+        let config_path = self.config_path();
+
+        check_name(Target::Skill, old_name)?;
+        if old_name == new_name {
+            return Ok(());
+        }
+        if !validate::is_valid_skill_name(new_name) {
+            return Err(AppError::invalid(Target::Skill, new_name));
+        }
+        for existing in [skills_dir.join(new_name), disabled_dir.join(new_name)] {
+            if existing.exists() {
+                return Err(AppError::already_exists(Target::Skill, new_name, &existing));
+            }
+        }
+
+        let src = find_skill_dir(&skills_dir, &disabled_dir, old_name)
+            .ok_or_else(|| AppError::not_found(Target::Skill, old_name))?;
+        let dst = src.with_file_name(new_name);
+
+        let mut config = self.load_config()?;
+        rename_in_categories(&mut config.categories, old_name, new_name);
+        for profile in config.profiles.values_mut() {
+            rename_in_list(&mut profile.skills, old_name, new_name);
+        }
+        let config_json = serde_json::to_string_pretty(&config)
+            .map_err(|e| AppError::parse(Target::Config, Some(&config_path), e))?;
+
+        Journal::run(|journal| {
+            journal.rename(&src, &dst).map_err(|e| AppError::io(&src, e))?;
+
+            let skill_md = dst.join("SKILL.md");
+            if let Ok(content) = fs::read_to_string(&skill_md) {
+                if let Some(updated) = frontmatter::set_name(&content, new_name) {
+                    journal.write(&skill_md, updated).map_err(|e| AppError::io(&skill_md, e))?;
+                }
+            }
+
+            journal.write(&config_path, &config_json).map_err(|e| AppError::io(&config_path, e))
+        })
+    }
+
+    pub fn duplicate_skill(&self, skill_name: &str, new_name: &str, enabled: bool) -> Result<(), AppError> {
+        check_name(Target::Skill, skill_name)?;
+        let skills_dir = self.skills_dir();
+        let disabled_dir = self.disabled_skills_dir();
+
+        if !validate::is_valid_skill_name(new_name) {
+            return Err(AppError::invalid(Target::Skill, new_name));
+        }
+        for existing in [skills_dir.join(new_name), disabled_dir.join(new_name)] {
+            if existing.exists() {
+                return Err(AppError::already_exists(Target::Skill, new_name, &existing));
+            }
+        }
+
+        let src = find_skill_dir(&skills_dir, &disabled_dir, skill_name)
+            .ok_or_else(|| AppError::not_found(Target::Skill, skill_name))?;
+        let dst = if enabled { skills_dir.join(new_name) } else { disabled_dir.join(new_name) };
+
+        let copy_result = copy_dir_all(&src, &dst)
+            .map_err(|e| AppError::io(&src, e))
+            .and_then(|_| {
+                let skill_md = dst.join("SKILL.md");
+                match fs::read_to_string(&skill_md) {
+                    Ok(content) => match frontmatter::set_name(&content, new_name) {
+                        Some(updated) => atomic::write(&skill_md, updated).map_err(|e| AppError::io(&skill_md, e)),
+                        None => Ok(()),
+                    },
+                    Err(_) => Ok(()),
+                }
+            });
+        if let Err(e) = copy_result {
+            // 途中までコピーしたものは新規作成分なので削除してよい
+            let _ = fs::remove_dir_all(&dst);
+            return Err(e);
+        }
+
+        // 元のスキルと同じカテゴリに登録
+        let mut config = self.load_config()?;
+        let category = config
+            .categories
+            .iter()
+            .find(|(_, names)| names.iter().any(|n| n == skill_name))
+            .map(|(category, _)| category.clone());
+        if let Some(category) = category {
+            config.categories.entry(category).or_default().push(new_name.to_string());
+            self.save_config(&config)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn delete_skill(&self, skill_name: &str) -> Result<TrashEntry, AppError> {
+        check_name(Target::Skill, skill_name)?;
+        let src = find_skill_dir(&self.skills_dir(), &self.disabled_skills_dir(), skill_name)
+            .ok_or_else(|| AppError::not_found(Target::Skill, skill_name))?;
+
+        let mut config = self.load_config()?;
+        let membership = Membership {
+            categories: remove_from_categories(&mut config.categories, skill_name),
+            profiles: remove_from_profiles(&mut config.profiles, |p| &mut p.skills, skill_name),
+        };
+        let entry = self.trash_item(&src, TrashKind::Skill, membership)?;
+        self.save_config(&config)?;
+
+        Ok(entry)
+    }
+
+    pub fn rename_slash_command(&self, old_name: &str, new_name: &str) -> Result<(), AppError> {
+        let commands_dir = self.commands_dir();
+        let disabled_dir = self.disabled_commands_dir();
+        let config_path = self.config_path();
+
+        check_name(Target::Command, old_name)?;
+        let new_name = new_name.trim();
+        if old_name == new_name {
+            return Ok(());
+        }
+        check_name(Target::Command, new_name)?;
+
+        let new_filename = format!("{}.md", new_name);
+        for existing in [commands_dir.join(&new_filename), disabled_dir.join(&new_filename)] {
+            if existing.exists() {
+                return Err(AppError::already_exists(Target::Command, new_name, &existing));
+            }
+        }
+
+        let src = find_command_file(&commands_dir, &disabled_dir, old_name)
+            .ok_or_else(|| AppError::not_found(Target::Command, old_name))?;
+        let dst = src.with_file_name(&new_filename);
+
+        let mut config = self.load_config()?;
+        rename_in_categories(&mut config.command_categories, old_name, new_name);
+        for profile in config.profiles.values_mut() {
+            rename_in_list(&mut profile.commands, old_name, new_name);
+        }
+        let config_json = serde_json::to_string_pretty(&config)
+            .map_err(|e| AppError::parse(Target::Config, Some(&config_path), e))?;
+
+        Journal::run(|journal| {
+            journal.rename(&src, &dst).map_err(|e| AppError::io(&src, e))?;
+
+            // コマンドはnameを持たないことが多いので、ある場合だけ書き換える
+            if let Ok(content) = fs::read_to_string(&dst) {
+                if let Some(updated) = frontmatter::set_name(&content, new_name) {
+                    journal.write(&dst, updated).map_err(|e| AppError::io(&dst, e))?;
+                }
+            }
+
+            journal.write(&config_path, &config_json).map_err(|e| AppError::io(&config_path, e))
+        })
+    }
+
+    pub fn delete_slash_command(&self, command_name: &str) -> Result<TrashEntry, AppError> {
+        check_name(Target::Command, command_name)?;
+        let src = find_command_file(&self.commands_dir(), &self.disabled_commands_dir(), command_name)
+            .ok_or_else(|| AppError::not_found(Target::Command, command_name))?;
+
+        let mut config = self.load_config()?;
+        let membership = Membership {
+            categories: remove_from_categories(&mut config.command_categories, command_name),
+            profiles: remove_from_profiles(&mut config.profiles, |p| &mut p.commands, command_name),
+        };
+        let entry = self.trash_item(&src, TrashKind::Command, membership)?;
+        self.save_config(&config)?;
+
+        Ok(entry)
+    }
+
+    // 設定のカテゴリとディスク上のスキル・コマンドを突き合わせる（fix=true なら設定を直す）
+    pub fn reconcile_config(&self, fix: bool) -> Result<ConfigReconcileReport, AppError> {
+        let mut config = self.load_config()?;
+        // プロジェクトの同名の項目に上書きされているユーザーの項目は数えない
+        let skill_names: Vec<String> =
+            self.load_skills()?.into_iter().filter(|s| !s.shadowed).map(|s| s.name).collect();
+        let command_names: Vec<String> =
+            self.load_slash_commands()?.into_iter().filter(|c| !c.shadowed).map(|c| c.name).collect();
+
+        let skills = reconcile::check(&config.categories, &skill_names);
+        let commands = reconcile::check(&config.command_categories, &command_names);
+
+        let needs_fix = !skills.orphaned.is_empty()
+            || !skills.unassigned.is_empty()
+            || !commands.orphaned.is_empty()
+            || !commands.unassigned.is_empty();
+        let fixed = fix && needs_fix;
+
+        if fixed {
+            // フロントエンドと同じく、未割り当ての項目は先頭のカテゴリに入れる
+            let default_category = config
+                .category_order
+                .first()
+                .cloned()
+                .unwrap_or_else(|| i18n::t(i18n::Key::DefaultCategory).to_string());
+            if !config.categories.contains_key(&default_category) {
+                config.categories.insert(default_category.clone(), Vec::new());
+            }
+            if !config.category_order.contains(&default_category) {
+                config.category_order.insert(0, default_category.clone());
+            }
+
+            reconcile::fix(&mut config.categories, &skills, &default_category);
+            reconcile::fix(&mut config.command_categories, &commands, &default_category);
+            self.save_config(&config)?;
+        }
+
+        Ok(ConfigReconcileReport { skills, commands, fixed })
+    }
+
+    // 別エージェント（.claude ↔ .codex）に同名のスキルがあるか
+    pub fn check_skill_conflict(&self, skill_name: &str, enabled: bool) -> Result<SkillConflictInfo, AppError> {
+        check_name(Target::Skill, skill_name)?;
+        let project_root = self.project_root().ok_or_else(AppError::not_in_project)?;
+
+        let current_type = self.agent_type();
+        let target_dir_name = match current_type.as_str() {
+            "claude" => ".codex",
+            "codex" => ".claude",
+            _ => return Err(AppError::invalid(Target::Agent, current_type)),
+        };
+
+        let target_agent_dir = project_root.join(target_dir_name);
+
+        if !target_agent_dir.exists() {
+            return Err(AppError::not_found(Target::AgentDir, target_dir_name));
+        }
+
+        // コピー元のパスを決定
+        let src_dir = if enabled {
+            self.skills_dir().join(skill_name)
+        } else {
+            self.disabled_skills_dir().join(skill_name)
+        };
+
+        if !src_dir.exists() {
+            return Err(AppError::not_found(Target::Skill, skill_name));
+        }
+
+        let target_skill_dir = target_agent_dir.join("skills").join(skill_name);
+        let target_disabled_dir = target_agent_dir.join("disabled-skills").join(skill_name);
+
+        let (exists, is_disabled, target_dir) = if target_skill_dir.exists() {
+            (true, false, target_skill_dir)
+        } else if target_disabled_dir.exists() {
+            (true, true, target_disabled_dir)
+        } else {
+            return Ok(SkillConflictInfo {
+                exists: false,
+                target_agent: target_dir_name.to_string(),
+                is_disabled: false,
+                source_modified: None,
+                target_modified: None,
+            });
+        };
+
+        Ok(SkillConflictInfo {
+            exists,
+            target_agent: target_dir_name.to_string(),
+            is_disabled,
+            source_modified: get_dir_modified_time(&src_dir),
+            target_modified: get_dir_modified_time(&target_dir),
+        })
+    }
+
+    pub fn list_trash(&self) -> Vec<TrashEntry> {
+        trash::list(&self.trash_dir())
+    }
+
+    // 元の場所に戻し、削除時に入っていたカテゴリ・プロファイルにも戻す
+    pub fn restore_from_trash(&self, id: &str) -> Result<TrashEntry, AppError> {
+        let entry = trash::restore(&self.trash_dir(), id, |scope| self.scope_root(scope))?;
+
+        let mut config = self.load_config()?;
+        let (categories, order) = match entry.kind {
+            TrashKind::Skill => (&mut config.categories, Some(&mut config.category_order)),
+            TrashKind::Command => (&mut config.command_categories, None),
+        };
+        let mut changed = false;
+        for category in &entry.membership.categories {
+            let names = categories.entry(category.clone()).or_default();
+            if !names.contains(&entry.name) {
+                names.push(entry.name.clone());
+                changed = true;
+            }
+        }
+        if let Some(order) = order {
+            for category in &entry.membership.categories {
+                if !order.contains(category) {
+                    order.push(category.clone());
+                }
+            }
+        }
+        for profile_name in &entry.membership.profiles {
+            // 削除後に消されたプロファイルは作り直さない
+            if let Some(profile) = config.profiles.get_mut(profile_name) {
+                let names = if entry.kind == TrashKind::Skill { &mut profile.skills } else { &mut profile.commands };
+                if !names.contains(&entry.name) {
+                    names.push(entry.name.clone());
+                    changed = true;
+                }
+            }
+        }
+        if changed {
+            self.save_config(&config)?;
+        }
+
+        Ok(entry)
+    }
+
+    pub fn empty_trash(&self) -> Result<(), AppError> {
+        let trash_dir = self.trash_dir();
+        trash::empty(&trash_dir).map_err(|e| AppError::io(&trash_dir, e))
+    }
+
+    // .skill / .zip のスキルを取り込み、スキル名を返す
+    pub fn import_skill_archive(&self, archive_path: &Path, enabled: bool, force: bool) -> Result<String, AppError> {
+        let skills_dir = self.skills_dir();
+        let disabled_dir = self.disabled_skills_dir();
+
+        let skill = archive::open(archive_path)?;
+        let name = skill.name.clone();
+
+        // 有効・無効どちらかに同名スキルがあれば衝突（上書き時はゴミ箱へ）
+        for existing in [skills_dir.join(&name), disabled_dir.join(&name)] {
+            if existing.exists() {
+                if !force {
+                    return Err(AppError::already_exists(Target::Skill, name, &existing));
+                }
+                self.trash_item(&existing, TrashKind::Skill, Membership::default())?;
+            }
+        }
+
+        let dst = if enabled { skills_dir.join(&name) } else { disabled_dir.join(&name) };
+        skill.extract(&dst)?;
+
+        Ok(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(path: &Path, content: &str) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn rejects_names_that_are_not_a_portable_file_name() {
+        let invalid = [
+            "", ".", "..", ".hidden", "a/b", "a\\b", "../a", "a:b", "a*b", "a?b", "a|b", "a<b", "a\"b", "a.", "a\tb",
+        ];
+        for name in invalid {
+            assert!(check_name(Target::Command, name).is_err(), "{:?}", name);
+        }
+        for name in ["review-pr", "commit_all", "日本語"] {
+            assert!(check_name(Target::Command, name).is_ok(), "{:?}", name);
+        }
+
+        let tmp = tempfile::tempdir().unwrap();
+        let base = tmp.path().join(".claude");
+        write(&base.join("commands/run.md"), "run");
+        let project = Project::new(base.clone());
+        assert!(project.rename_slash_command("run", "a:b").is_err());
+        assert!(base.join("commands/run.md").is_file());
+    }
+
+    #[test]
+    fn create_skill_leaves_nothing_behind_on_failure() {
+        let tmp = tempfile::tempdir().unwrap();
+        let base = tmp.path().join(".claude");
+        fs::create_dir_all(&base).unwrap();
+        let project = Project::new(base.clone());
+
+        assert!(project.create_skill("sample", "desc", "  ", true, false, false).is_err());
+        assert!(!base.join("skills/sample").exists());
+        assert!(!base.join(CONFIG_FILE_NAME).exists());
+
+        // 設定ファイルを書けない（一時ファイルの場所にフォルダがある）と、作ったフォルダも消す
+        let temp = format!(".{}.{}.tmp", CONFIG_FILE_NAME, std::process::id());
+        write(&base.join(temp).join("keep"), "");
+        assert!(project.create_skill("sample", "desc", "dev", true, false, false).is_err());
+        assert!(!base.join("skills/sample").exists());
+    }
+
+    #[test]
+    fn move_all_undoes_earlier_moves_when_one_fails() {
+        let tmp = tempfile::tempdir().unwrap();
+        let (from, to) = (tmp.path().join("skills"), tmp.path().join("disabled-skills"));
+        write(&from.join("a/SKILL.md"), "a");
+        write(&from.join("b/SKILL.md"), "b");
+
+        // 2つ目の移動元がないので、1つ目を動かしたあとで失敗する
+        let moves = [
+            (from.join("a"), to.join("a")),
+            (from.join("missing"), to.join("missing")),
+            (from.join("b"), to.join("b")),
+        ];
+        assert!(move_all(&moves).is_err());
+
+        assert!(from.join("a/SKILL.md").is_file());
+        assert!(from.join("b/SKILL.md").is_file());
+        assert!(!to.join("a").exists());
+    }
+
+    // 移動先のフォルダがファイルへのシンボリックリンクだと、スキルを動かしたあとのコマンドの移動で失敗する
+    // （root でも失敗する）。先に動かしたスキルも元に戻る
+    #[cfg(unix)]
+    #[test]
+    fn toggle_many_restores_everything_when_a_move_fails() {
+        let tmp = tempfile::tempdir().unwrap();
+        let base = tmp.path().join(".claude");
+        write(&base.join("skills/a/SKILL.md"), "---\nname: a\ndescription: a\n---\n");
+        write(&base.join("commands/run.md"), "run");
+        write(&tmp.path().join("not-a-dir"), "");
+        std::os::unix::fs::symlink(tmp.path().join("not-a-dir"), base.join("disabled-commands")).unwrap();
+
+        let project = Project::new(base.clone());
+        let target = |name: &str| ToggleTarget { name: name.to_string(), scope: Scope::Project };
+        assert!(project.toggle_many(&[target("a")], &[target("run")], false).is_err());
+
+        assert!(base.join("skills/a/SKILL.md").is_file());
+        assert!(!base.join("disabled-skills/a").exists());
+        assert!(base.join("commands/run.md").is_file());
+    }
+}
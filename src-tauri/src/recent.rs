@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::atomic;
+use crate::project::Project;
+
+const RECENT_FILE_NAME: &str = "recent-projects.json";
+const MAX_RECENT: usize = 10;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RecentProject {
+    // プロジェクトのルート（.claude/ の親）
+    pub path: String,
+    pub agent: String,
+    pub opened_at: String,
+}
+
+// 最近開いたプロジェクト（新しいものが先頭）。アプリの設定ディレクトリに保存する
+pub fn load(config_dir: &Path) -> Vec<RecentProject> {
+    fs::read_to_string(config_dir.join(RECENT_FILE_NAME))
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+fn save(config_dir: &Path, recent: &[RecentProject]) -> io::Result<()> {
+    fs::create_dir_all(config_dir)?;
+    let json = serde_json::to_string_pretty(recent)?;
+    atomic::write(&config_dir.join(RECENT_FILE_NAME), json)
+}
+
+pub fn record(config_dir: &Path, project: &Project) -> io::Result<()> {
+    let Some(root) = project.project_root() else { return Ok(()) };
+    let path = root.to_string_lossy().to_string();
+
+    let mut recent = load(config_dir);
+    recent.retain(|r| r.path != path);
+    recent.insert(
+        0,
+        RecentProject {
+            path,
+            agent: project.agent_type(),
+            opened_at: chrono::Local::now().format("%Y/%m/%d %H:%M").to_string(),
+        },
+    );
+    recent.truncate(MAX_RECENT);
+    save(config_dir, &recent)
+}
+
+pub fn remove(config_dir: &Path, path: &str) -> io::Result<()> {
+    let mut recent = load(config_dir);
+    recent.retain(|r| r.path != path);
+    save(config_dir, &recent)
+}
@@ -0,0 +1,69 @@
+use indexmap::IndexMap;
+use serde::Serialize;
+use std::collections::HashSet;
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DuplicateAssignment {
+    pub name: String,
+    pub categories: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ReconcileReport {
+    // 設定にあるがディスクに存在しない
+    pub orphaned: Vec<String>,
+    // ディスクにあるがどのカテゴリにも入っていない
+    pub unassigned: Vec<String>,
+    // 複数のカテゴリに入っている
+    pub duplicated: Vec<DuplicateAssignment>,
+}
+
+// categories をディスク上の names と突き合わせる
+pub fn check(categories: &IndexMap<String, Vec<String>>, names: &[String]) -> ReconcileReport {
+    let existing: HashSet<&str> = names.iter().map(String::as_str).collect();
+    let mut report = ReconcileReport::default();
+    let mut assigned: IndexMap<&str, Vec<String>> = IndexMap::new();
+
+    for (category, items) in categories {
+        for item in items {
+            let categories = assigned.entry(item.as_str()).or_default();
+            if !categories.contains(category) {
+                categories.push(category.clone());
+            }
+        }
+    }
+
+    for (name, categories) in &assigned {
+        if !existing.contains(name) {
+            report.orphaned.push(name.to_string());
+        }
+        if categories.len() > 1 {
+            report.duplicated.push(DuplicateAssignment { name: name.to_string(), categories: categories.clone() });
+        }
+    }
+
+    // 同じ名前が複数回渡されても1回だけ数える
+    let mut seen = HashSet::new();
+    report.unassigned = names
+        .iter()
+        .filter(|name| !assigned.contains_key(name.as_str()) && seen.insert(name.as_str()))
+        .cloned()
+        .collect();
+    report
+}
+
+// 存在しない項目を外し、未割り当ての項目を default_category に追加する
+// 複数カテゴリへの割り当ては意図的な場合もあるのでそのままにする
+pub fn fix(categories: &mut IndexMap<String, Vec<String>>, report: &ReconcileReport, default_category: &str) {
+    for items in categories.values_mut() {
+        items.retain(|item| !report.orphaned.contains(item));
+    }
+    if !report.unassigned.is_empty() {
+        categories
+            .entry(default_category.to_string())
+            .or_default()
+            .extend(report.unassigned.iter().cloned());
+    }
+}
@@ -0,0 +1,189 @@
+use std::fs;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+
+use crate::error::AppError;
+
+#[derive(Debug)]
+pub enum SandboxError {
+    // 許可されたディレクトリの外を指している
+    PermissionDenied(PathBuf),
+    Io(io::Error),
+}
+
+// 表示用のメッセージは AppError 側で翻訳する
+impl From<SandboxError> for AppError {
+    fn from(e: SandboxError) -> Self {
+        match e {
+            SandboxError::PermissionDenied(path) => AppError::permission_denied(&path),
+            SandboxError::Io(e) => AppError::io_message(e),
+        }
+    }
+}
+
+fn denied(path: &Path) -> SandboxError {
+    SandboxError::PermissionDenied(path.to_path_buf())
+}
+
+// path をシンボリックリンクまで解決し、roots のいずれかの内側にあるときだけ返す
+// まだ存在しないファイル（新規保存）は親ディレクトリで判定する
+pub fn resolve(roots: &[PathBuf], path: &Path) -> Result<PathBuf, SandboxError> {
+    if path.components().any(|c| c == Component::ParentDir) {
+        return Err(denied(path));
+    }
+
+    let canonical_roots: Vec<PathBuf> = roots.iter().filter_map(|root| root.canonicalize().ok()).collect();
+    if canonical_roots.is_empty() {
+        return Err(denied(path));
+    }
+
+    let path = if path.is_absolute() { path.to_path_buf() } else { roots[0].join(path) };
+
+    let resolved = match path.canonicalize() {
+        Ok(resolved) => resolved,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            // リンク先が存在しないシンボリックリンクは、書き込むと外に作られる可能性がある
+            if fs::symlink_metadata(&path).is_ok() {
+                return Err(denied(&path));
+            }
+            let parent = path.parent().ok_or_else(|| denied(&path))?;
+            let file_name = path.file_name().ok_or_else(|| denied(&path))?;
+            parent.canonicalize().map_err(SandboxError::Io)?.join(file_name)
+        }
+        Err(e) => return Err(SandboxError::Io(e)),
+    };
+
+    if canonical_roots.iter().any(|root| resolved.starts_with(root)) {
+        Ok(resolved)
+    } else {
+        Err(denied(&path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    struct Fixture {
+        _dir: TempDir,
+        base: PathBuf,
+        outside: PathBuf,
+    }
+
+    // <tmp>/project/.claude/skills/sample/SKILL.md と <tmp>/outside/secret.txt
+    fn fixture() -> Fixture {
+        let dir = TempDir::new().unwrap();
+        let base = dir.path().join("project").join(".claude");
+        let outside = dir.path().join("outside");
+        fs::create_dir_all(base.join("skills").join("sample")).unwrap();
+        fs::create_dir_all(&outside).unwrap();
+        fs::write(base.join("skills").join("sample").join("SKILL.md"), "---\n---\n").unwrap();
+        fs::write(outside.join("secret.txt"), "secret").unwrap();
+        Fixture { _dir: dir, base, outside }
+    }
+
+    fn is_denied(result: Result<PathBuf, SandboxError>) -> bool {
+        matches!(result, Err(SandboxError::PermissionDenied(_)))
+    }
+
+    #[test]
+    fn allows_existing_file_inside_project() {
+        let f = fixture();
+        let path = f.base.join("skills").join("sample").join("SKILL.md");
+        let resolved = resolve(std::slice::from_ref(&f.base), &path).unwrap();
+        assert_eq!(resolved, path.canonicalize().unwrap());
+    }
+
+    #[test]
+    fn allows_new_file_inside_project() {
+        let f = fixture();
+        let path = f.base.join("skills").join("sample").join("notes.md");
+        assert!(resolve(std::slice::from_ref(&f.base), &path).is_ok());
+    }
+
+    #[test]
+    fn resolves_relative_paths_against_the_project() {
+        let f = fixture();
+        let resolved = resolve(std::slice::from_ref(&f.base), Path::new("skills/sample/SKILL.md")).unwrap();
+        assert!(resolved.ends_with("skills/sample/SKILL.md"));
+    }
+
+    #[test]
+    fn rejects_absolute_path_outside_project() {
+        let f = fixture();
+        assert!(is_denied(resolve(std::slice::from_ref(&f.base), &f.outside.join("secret.txt"))));
+    }
+
+    #[test]
+    fn rejects_new_file_outside_project() {
+        let f = fixture();
+        assert!(is_denied(resolve(std::slice::from_ref(&f.base), &f.outside.join("new.txt"))));
+    }
+
+    #[test]
+    fn rejects_parent_dir_traversal() {
+        let f = fixture();
+        let path = f.base.join("skills").join("..").join("..").join("..").join("outside").join("secret.txt");
+        assert!(is_denied(resolve(std::slice::from_ref(&f.base), &path)));
+    }
+
+    #[test]
+    fn rejects_parent_dir_traversal_that_stays_inside() {
+        let f = fixture();
+        let path = f.base.join("skills").join("sample").join("..").join("sample").join("SKILL.md");
+        assert!(is_denied(resolve(std::slice::from_ref(&f.base), &path)));
+    }
+
+    #[test]
+    fn rejects_relative_parent_dir_traversal() {
+        let f = fixture();
+        assert!(is_denied(resolve(std::slice::from_ref(&f.base), Path::new("../../outside/secret.txt"))));
+    }
+
+    #[test]
+    fn rejects_sibling_directory_with_common_prefix() {
+        let f = fixture();
+        let sibling = f.base.with_file_name(".claude-other");
+        fs::create_dir_all(&sibling).unwrap();
+        fs::write(sibling.join("file.md"), "x").unwrap();
+        assert!(is_denied(resolve(std::slice::from_ref(&f.base), &sibling.join("file.md"))));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn rejects_symlinked_file_pointing_outside() {
+        let f = fixture();
+        let link = f.base.join("skills").join("sample").join("link.txt");
+        std::os::unix::fs::symlink(f.outside.join("secret.txt"), &link).unwrap();
+        assert!(is_denied(resolve(std::slice::from_ref(&f.base), &link)));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn rejects_file_inside_symlinked_directory_pointing_outside() {
+        let f = fixture();
+        let link = f.base.join("skills").join("escape");
+        std::os::unix::fs::symlink(&f.outside, &link).unwrap();
+        assert!(is_denied(resolve(std::slice::from_ref(&f.base), &link.join("secret.txt"))));
+        assert!(is_denied(resolve(std::slice::from_ref(&f.base), &link.join("new.txt"))));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn rejects_dangling_symlink() {
+        let f = fixture();
+        let link = f.base.join("skills").join("sample").join("dangling.txt");
+        std::os::unix::fs::symlink(f.outside.join("does-not-exist.txt"), &link).unwrap();
+        assert!(is_denied(resolve(std::slice::from_ref(&f.base), &link)));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn allows_symlink_that_stays_inside_project() {
+        let f = fixture();
+        let link = f.base.join("skills").join("alias");
+        std::os::unix::fs::symlink(f.base.join("skills").join("sample"), &link).unwrap();
+        assert!(resolve(std::slice::from_ref(&f.base), &link.join("SKILL.md")).is_ok());
+    }
+}
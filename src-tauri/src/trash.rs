@@ -0,0 +1,254 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+
+use crate::atomic;
+use crate::error::{AppError, Target};
+use crate::project::Scope;
+use crate::copy_dir_all;
+
+pub const TRASH_DIR_NAME: &str = ".skillsmanager-trash";
+const ENTRY_FILE_NAME: &str = "entry.json";
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum TrashKind {
+    Skill,
+    Command,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TrashEntry {
+    pub id: String,
+    pub name: String,
+    pub kind: TrashKind,
+    // 元の場所。プロジェクトの項目はプロジェクトのルート、ユーザーの項目はホームからの相対パス（".claude/skills/foo"）
+    pub original_path: String,
+    #[serde(default)]
+    pub scope: Scope,
+    #[serde(flatten)]
+    pub membership: Membership,
+    pub deleted_at: String,
+}
+
+// 削除時に入っていたカテゴリとプロファイル（復元時に戻す）
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Membership {
+    #[serde(default)]
+    pub categories: Vec<String>,
+    #[serde(default)]
+    pub profiles: Vec<String>,
+}
+
+// rename できない（別ボリュームなど）場合はコピーしてから削除する
+pub(crate) fn move_path(src: &Path, dst: &Path) -> io::Result<()> {
+    if fs::rename(src, dst).is_ok() {
+        return Ok(());
+    }
+    if src.is_dir() {
+        copy_dir_all(&src.to_path_buf(), &dst.to_path_buf())?;
+        fs::remove_dir_all(src)
+    } else {
+        fs::copy(src, dst)?;
+        fs::remove_file(src)
+    }
+}
+
+fn new_entry_dir(trash_dir: &Path) -> io::Result<(String, PathBuf)> {
+    let timestamp = chrono::Local::now().format("%Y%m%d-%H%M%S%3f").to_string();
+    let mut id = timestamp.clone();
+    let mut suffix = 1;
+    while trash_dir.join(&id).exists() {
+        id = format!("{}-{}", timestamp, suffix);
+        suffix += 1;
+    }
+    let dir = trash_dir.join(&id);
+    fs::create_dir_all(&dir)?;
+    Ok((id, dir))
+}
+
+// path をゴミ箱の <timestamp>/ に移動し、元の場所（root からの相対パス）を entry.json に記録する
+pub fn move_to_trash(
+    trash_dir: &Path,
+    root: &Path,
+    scope: Scope,
+    path: &Path,
+    kind: TrashKind,
+    membership: Membership,
+) -> io::Result<TrashEntry> {
+    let relative = path
+        .strip_prefix(root)
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "invalid path"))?;
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "invalid path"))?;
+    let name = match kind {
+        TrashKind::Skill => file_name.to_string_lossy().to_string(),
+        TrashKind::Command => path
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_default(),
+    };
+
+    let (id, entry_dir) = new_entry_dir(trash_dir)?;
+    let entry = TrashEntry {
+        id,
+        name,
+        kind,
+        original_path: relative.to_string_lossy().to_string(),
+        scope,
+        membership,
+        deleted_at: chrono::Local::now().format("%Y/%m/%d %H:%M:%S").to_string(),
+    };
+
+    let json = serde_json::to_string_pretty(&entry)?;
+    atomic::write(&entry_dir.join(ENTRY_FILE_NAME), json)?;
+
+    if let Err(e) = move_path(path, &entry_dir.join(file_name)) {
+        let _ = fs::remove_dir_all(&entry_dir);
+        return Err(e);
+    }
+
+    Ok(entry)
+}
+
+pub fn list(trash_dir: &Path) -> Vec<TrashEntry> {
+    let mut entries = Vec::new();
+    if let Ok(dirs) = fs::read_dir(trash_dir) {
+        for dir in dirs.filter_map(|e| e.ok()) {
+            let entry = fs::read_to_string(dir.path().join(ENTRY_FILE_NAME))
+                .ok()
+                .and_then(|json| serde_json::from_str::<TrashEntry>(&json).ok());
+            if let Some(entry) = entry {
+                entries.push(entry);
+            }
+        }
+    }
+    // 新しいものが先頭
+    entries.sort_by(|a, b| b.id.cmp(&a.id));
+    entries
+}
+
+impl From<TrashKind> for Target {
+    fn from(kind: TrashKind) -> Self {
+        match kind {
+            TrashKind::Skill => Target::Skill,
+            TrashKind::Command => Target::Command,
+        }
+    }
+}
+
+// 復元先は "<.claude|.codex>/<skills|disabled-skills|commands|disabled-commands>/<名前>" だけを許す
+// （entry.json は書き換えられるので、それ以外の場所には書き込まない）
+fn restore_path(root: &Path, entry: &TrashEntry) -> Option<PathBuf> {
+    let original = Path::new(&entry.original_path);
+    // 以前の entry.json は絶対パスを持っている
+    let relative = original.strip_prefix(root).unwrap_or(original);
+    let parts = relative
+        .components()
+        .map(|c| match c {
+            Component::Normal(part) => part.to_str(),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    let (dirs, file_name) = match entry.kind {
+        TrashKind::Skill => (["skills", "disabled-skills"], entry.name.clone()),
+        TrashKind::Command => (["commands", "disabled-commands"], format!("{}.md", entry.name)),
+    };
+    match parts.as_slice() {
+        [agent, dir, name] if [".claude", ".codex"].contains(agent) && dirs.contains(dir) && *name == file_name => {
+            Some(root.join(relative))
+        }
+        _ => None,
+    }
+}
+
+// root_of: scope に対応する original_path の基準ディレクトリ
+pub fn restore(trash_dir: &Path, id: &str, root_of: impl Fn(Scope) -> Option<PathBuf>) -> Result<TrashEntry, AppError> {
+    if id.is_empty() || id.contains(['/', '\\']) || id.starts_with('.') {
+        return Err(AppError::invalid(Target::TrashEntry, id));
+    }
+
+    let entry_dir = trash_dir.join(id);
+    let entry_path = entry_dir.join(ENTRY_FILE_NAME);
+    let json = fs::read_to_string(&entry_path).map_err(|_| AppError::not_found(Target::TrashEntry, id))?;
+    let entry: TrashEntry =
+        serde_json::from_str(&json).map_err(|e| AppError::parse(Target::TrashEntry, Some(&entry_path), e))?;
+
+    let original = root_of(entry.scope)
+        .and_then(|root| restore_path(&root, &entry))
+        .ok_or_else(|| AppError::invalid(Target::TrashEntry, id))?;
+    if original.exists() {
+        return Err(AppError::already_exists(entry.kind, entry.name.as_str(), &original));
+    }
+    let file_name = original.file_name().ok_or_else(|| AppError::invalid(Target::TrashEntry, id))?;
+
+    if let Some(parent) = original.parent() {
+        fs::create_dir_all(parent).map_err(|e| AppError::io(parent, e))?;
+    }
+    move_path(&entry_dir.join(file_name), &original).map_err(|e| AppError::io(&original, e))?;
+    fs::remove_dir_all(&entry_dir).map_err(|e| AppError::io(&entry_dir, e))?;
+
+    Ok(entry)
+}
+
+pub fn empty(trash_dir: &Path) -> io::Result<()> {
+    if trash_dir.exists() {
+        fs::remove_dir_all(trash_dir)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trash_skill(root: &Path) -> (PathBuf, TrashEntry) {
+        let skill = root.join(".claude/skills/sample");
+        fs::create_dir_all(&skill).unwrap();
+        fs::write(skill.join("SKILL.md"), "---\nname: sample\n---\n").unwrap();
+        let trash_dir = root.join(".claude").join(TRASH_DIR_NAME);
+        let entry = move_to_trash(&trash_dir, root, Scope::Project, &skill, TrashKind::Skill, Membership::default())
+            .unwrap();
+        (trash_dir, entry)
+    }
+
+    #[test]
+    fn restores_to_the_relative_original_path() {
+        let tmp = tempfile::tempdir().unwrap();
+        let root = tmp.path();
+        let (trash_dir, entry) = trash_skill(root);
+        assert_eq!(Path::new(&entry.original_path), Path::new(".claude/skills/sample"));
+
+        restore(&trash_dir, &entry.id, |_| Some(root.to_path_buf())).unwrap();
+        assert!(root.join(".claude/skills/sample/SKILL.md").is_file());
+    }
+
+    #[test]
+    fn rejects_forged_original_path() {
+        let tmp = tempfile::tempdir().unwrap();
+        let root = tmp.path().join("project");
+        let (trash_dir, entry) = trash_skill(&root);
+        let outside = tmp.path().join("outside");
+
+        let forged = [
+            "../outside/sample".to_string(),
+            outside.join("sample").to_string_lossy().to_string(),
+            ".claude/agents/sample".to_string(),
+            ".claude/skills/other".to_string(),
+        ];
+        for original_path in forged {
+            let forged = TrashEntry { original_path, ..entry.clone() };
+            let json = serde_json::to_string(&forged).unwrap();
+            fs::write(trash_dir.join(&entry.id).join(ENTRY_FILE_NAME), json).unwrap();
+
+            assert!(restore(&trash_dir, &entry.id, |_| Some(root.clone())).is_err());
+        }
+        assert!(!outside.exists());
+        assert!(!root.join(".claude/skills/sample").exists());
+    }
+}
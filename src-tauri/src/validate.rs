@@ -0,0 +1,300 @@
+use serde::Serialize;
+use std::path::Path;
+
+use crate::frontmatter;
+use crate::i18n::{self, Key};
+use crate::Skill;
+
+// Agent Skillsの仕様上の上限
+const MAX_NAME_LENGTH: usize = 64;
+const MAX_DESCRIPTION_LENGTH: usize = 1024;
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SkillDiagnostic {
+    pub skill: String,
+    pub severity: Severity,
+    pub file: String,
+    pub line: Option<usize>,
+    pub rule: String,
+    pub message: String,
+}
+
+struct Collector<'a> {
+    skill: &'a Skill,
+    diagnostics: Vec<SkillDiagnostic>,
+}
+
+impl Collector<'_> {
+    fn push(&mut self, severity: Severity, line: Option<usize>, rule: &str, message: String) {
+        self.diagnostics.push(SkillDiagnostic {
+            skill: self.skill.name.clone(),
+            severity,
+            file: self.skill.path.clone(),
+            line,
+            rule: rule.to_string(),
+            message,
+        });
+    }
+}
+
+pub fn is_valid_skill_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.chars().count() <= MAX_NAME_LENGTH
+        && name.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        && !name.starts_with('-')
+        && !name.ends_with('-')
+        && !name.contains("--")
+}
+
+// frontmatterの "key:" がある行を探す（診断の行番号用）
+fn find_key_line(content: &str, key: &str) -> Option<usize> {
+    let prefix = format!("{}:", key);
+    content
+        .lines()
+        .skip(1)
+        .take_while(|line| line.trim_end() != "---")
+        .position(|line| line.starts_with(&prefix))
+        .map(|index| index + 2)
+}
+
+// 本文中のMarkdownリンクのうち、スキル内の相対パスを参照しているものを返す
+fn relative_links(body: &str) -> Vec<(usize, String)> {
+    let mut links = Vec::new();
+    let mut in_code_block = false;
+
+    for (index, line) in body.lines().enumerate() {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            continue;
+        }
+
+        // インラインコードは対象外
+        let text: String = line
+            .split('`')
+            .enumerate()
+            .filter(|(i, _)| i % 2 == 0)
+            .map(|(_, part)| part)
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let mut rest = text.as_str();
+        while let Some(start) = rest.find("](") {
+            rest = &rest[start + 2..];
+            let Some(end) = rest.find(')') else { break };
+            let target = rest[..end].split_whitespace().next().unwrap_or("");
+            let target = target.trim_start_matches('<').trim_end_matches('>');
+            let target = target.split(['#', '?']).next().unwrap_or("");
+            rest = &rest[end + 1..];
+
+            if target.is_empty()
+                || target.contains("://")
+                || target.starts_with("mailto:")
+                || target.starts_with('/')
+            {
+                continue;
+            }
+            links.push((index, target.replace("%20", " ")));
+        }
+    }
+
+    links
+}
+
+pub fn validate_skill(skill: &Skill) -> Vec<SkillDiagnostic> {
+    let mut collector = Collector { skill, diagnostics: Vec::new() };
+    let locale = i18n::current();
+    let text = |key| i18n::text(locale, key).to_string();
+    let skill_file = Path::new(&skill.path);
+
+    if skill_file.file_name().map(|n| n != "SKILL.md").unwrap_or(false) {
+        collector.push(
+            Severity::Warning,
+            None,
+            "skill-file-name",
+            text(Key::SkillFileName),
+        );
+    }
+
+    let document = match frontmatter::parse(&skill.content) {
+        Ok(document) => document,
+        Err(e) => {
+            collector.push(Severity::Error, e.line, "frontmatter-invalid", e.message);
+            return collector.diagnostics;
+        }
+    };
+    for e in &document.field_errors {
+        collector.push(Severity::Error, e.line, "frontmatter-invalid", e.message.clone());
+    }
+
+    match &document.frontmatter {
+        None => {
+            collector.push(
+                Severity::Error,
+                Some(1),
+                "frontmatter-missing",
+                text(Key::FrontmatterMissing),
+            );
+        }
+        Some(frontmatter) => {
+            match frontmatter.name.as_deref().map(str::trim) {
+                None | Some("") => collector.push(
+                    Severity::Error,
+                    None,
+                    "name-missing",
+                    text(Key::NameMissing),
+                ),
+                Some(name) => {
+                    let line = find_key_line(&skill.content, "name");
+                    if name.chars().count() > MAX_NAME_LENGTH {
+                        collector.push(
+                            Severity::Error,
+                            line,
+                            "name-too-long",
+                            i18n::format(locale, Key::NameTooLong, &[("max", &MAX_NAME_LENGTH.to_string())]),
+                        );
+                    } else if !is_valid_skill_name(name) {
+                        collector.push(
+                            Severity::Error,
+                            line,
+                            "name-format",
+                            text(Key::NameFormat),
+                        );
+                    }
+                    if name != skill.name {
+                        collector.push(
+                            Severity::Error,
+                            line,
+                            "name-mismatch",
+                            i18n::format(locale, Key::NameMismatch, &[("name", name), ("dir", &skill.name)]),
+                        );
+                    }
+                }
+            }
+
+            match frontmatter.description.as_deref().map(str::trim) {
+                None | Some("") => collector.push(
+                    Severity::Error,
+                    None,
+                    "description-missing",
+                    text(Key::DescriptionMissing),
+                ),
+                Some(description) if description.chars().count() > MAX_DESCRIPTION_LENGTH => {
+                    collector.push(
+                        Severity::Error,
+                        find_key_line(&skill.content, "description"),
+                        "description-too-long",
+                        i18n::format(locale, Key::DescriptionTooLong, &[("max", &MAX_DESCRIPTION_LENGTH.to_string())]),
+                    );
+                }
+                Some(_) => {}
+            }
+        }
+    }
+
+    if let Some(skill_dir) = skill_file.parent() {
+        for (index, target) in relative_links(document.body) {
+            if !skill_dir.join(&target).exists() {
+                collector.push(
+                    Severity::Error,
+                    Some(document.body_line + index),
+                    "missing-reference",
+                    i18n::format(locale, Key::MissingReference, &[("path", &target)]),
+                );
+            }
+        }
+    }
+
+    collector.diagnostics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    // temp の skills/sample/<file_name> に content を書いて検証し、(rule, 行番号) を返す
+    fn check(file_name: &str, content: &str) -> Vec<(String, Option<usize>)> {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path().join("skills/sample");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("reference.md"), "").unwrap();
+        fs::write(dir.join("with space.md"), "").unwrap();
+        let path = dir.join(file_name);
+        fs::write(&path, content).unwrap();
+
+        let skill = Skill {
+            name: "sample".to_string(),
+            description: String::new(),
+            enabled: true,
+            content: content.to_string(),
+            path: path.to_string_lossy().to_string(),
+            files: Vec::new(),
+            content_token: String::new(),
+            frontmatter: None,
+            frontmatter_error: None,
+            scope: Default::default(),
+            shadows: false,
+            shadowed: false,
+            duplicate: false,
+        };
+        validate_skill(&skill).into_iter().map(|d| (d.rule, d.line)).collect()
+    }
+
+    fn assert_rules(file_name: &str, content: &str, expected: &[(&str, Option<usize>)]) {
+        let expected: Vec<_> = expected.iter().map(|(rule, line)| (rule.to_string(), *line)).collect();
+        assert_eq!(check(file_name, content), expected, "{:?}", content);
+    }
+
+    #[test]
+    fn reports_frontmatter_rules_with_line_numbers() {
+        assert_rules("SKILL.md", "---\nname: sample\ndescription: ok\n---\nbody\n", &[]);
+        assert_rules("skill.md", "---\nname: sample\ndescription: ok\n---\n", &[("skill-file-name", None)]);
+        assert_rules("SKILL.md", "# sample\n", &[("frontmatter-missing", Some(1))]);
+        assert_rules("SKILL.md", "---\nname: sample\n", &[("frontmatter-invalid", Some(1))]);
+        assert_rules("SKILL.md", "---\ndescription: ok\n---\n", &[("name-missing", None)]);
+        assert_rules(
+            "SKILL.md",
+            "---\ndescription: ok\nname: Sample\n---\n",
+            &[("name-format", Some(3)), ("name-mismatch", Some(3))],
+        );
+        assert_rules(
+            "SKILL.md",
+            "---\nname: sample-\ndescription: ok\n---\n",
+            &[("name-format", Some(2)), ("name-mismatch", Some(2))],
+        );
+        assert_rules("SKILL.md", "---\nname: other\ndescription: ok\n---\n", &[("name-mismatch", Some(2))]);
+        assert_rules(
+            "SKILL.md",
+            &format!("---\nname: {}\ndescription: ok\n---\n", "a".repeat(MAX_NAME_LENGTH + 1)),
+            &[("name-too-long", Some(2)), ("name-mismatch", Some(2))],
+        );
+        assert_rules("SKILL.md", "---\nname: sample\ndescription: \"  \"\n---\n", &[("description-missing", None)]);
+        assert_rules(
+            "SKILL.md",
+            &format!("---\nname: sample\ndescription: {}\n---\n", "x".repeat(MAX_DESCRIPTION_LENGTH + 1)),
+            &[("description-too-long", Some(3))],
+        );
+    }
+
+    #[test]
+    fn reports_only_missing_relative_links() {
+        let content = "---\nname: sample\ndescription: ok\n---\n\
+            [ok](reference.md) [anchor](reference.md#usage) [space](with%20space.md)\n\
+            [url](https://example.com/gone.md) [mail](mailto:a@example.com) [abs](/gone.md) [here](#usage)\n\
+            `[inline](gone.md)`\n\
+            ```\n[block](gone.md)\n```\n\
+            [missing](gone.md)\n";
+        assert_rules("SKILL.md", content, &[("missing-reference", Some(11))]);
+    }
+}
@@ -0,0 +1,187 @@
+use notify_debouncer_mini::notify::{RecommendedWatcher, RecursiveMode};
+use notify_debouncer_mini::{new_debouncer, DebounceEventResult, Debouncer};
+use serde::Serialize;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+
+pub const CHANGE_EVENT: &str = "skills-changed";
+const DEBOUNCE: Duration = Duration::from_millis(500);
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum ChangeTarget {
+    Skill,
+    Command,
+    Config,
+}
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ChangeKind {
+    Added,
+    Changed,
+    Removed,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FsChange {
+    pub target: ChangeTarget,
+    pub name: Option<String>,
+    pub change: ChangeKind,
+    // スキル・コマンドの場合、変更後に有効かどうか
+    pub enabled: Option<bool>,
+}
+
+type Item = (ChangeTarget, String);
+
+// 監視中のプロジェクト（プロジェクト切り替え時に差し替える）
+#[derive(Default)]
+pub struct WatcherState(Mutex<Option<Debouncer<RecommendedWatcher>>>);
+
+impl WatcherState {
+    // 変更があるたびに on_change を呼ぶ（GUIではフロントエンドに CHANGE_EVENT を送る）
+    pub fn watch(
+        &self,
+        base_dir: &Path,
+        on_change: impl Fn(Vec<FsChange>) + Send + 'static,
+    ) -> Result<(), String> {
+        let debouncer = start(base_dir.to_path_buf(), on_change)
+            .map_err(|e| format!("Failed to watch {}: {}", base_dir.display(), e))?;
+        *self.0.lock().map_err(|e| e.to_string())? = Some(debouncer);
+        Ok(())
+    }
+}
+
+// base_dir からの相対パスを、どのスキル・コマンドの変更かに変換する
+fn classify(base_dir: &Path, path: &Path) -> Option<Item> {
+    let relative = path.strip_prefix(base_dir).ok()?;
+    let mut components = relative.components().filter_map(|c| match c {
+        Component::Normal(name) => Some(name.to_string_lossy().to_string()),
+        _ => None,
+    });
+    let first = components.next()?;
+    let second = components.next();
+    let depth = 2 + components.count();
+
+    match (first.as_str(), second) {
+        ("skillsmanager-config.json", None) => Some((ChangeTarget::Config, String::new())),
+        ("skills" | "disabled-skills", Some(name)) => Some((ChangeTarget::Skill, name)),
+        // load_slash_commandsと同じく直下の .md のみ
+        ("commands" | "disabled-commands", Some(file)) if depth == 2 => {
+            let file = Path::new(&file);
+            let is_md = file.extension().map(|e| e.eq_ignore_ascii_case("md")).unwrap_or(false);
+            let stem = file.file_stem()?.to_string_lossy().to_string();
+            is_md.then_some((ChangeTarget::Command, stem))
+        }
+        _ => None,
+    }
+}
+
+// 変更後の状態: None=存在しない, Some(enabled)
+fn current_state(base_dir: &Path, item: &Item) -> Option<bool> {
+    let (target, name) = item;
+    match target {
+        ChangeTarget::Skill => {
+            if base_dir.join("skills").join(name).is_dir() {
+                Some(true)
+            } else if base_dir.join("disabled-skills").join(name).is_dir() {
+                Some(false)
+            } else {
+                None
+            }
+        }
+        ChangeTarget::Command => {
+            let filename = format!("{}.md", name);
+            if base_dir.join("commands").join(&filename).is_file() {
+                Some(true)
+            } else if base_dir.join("disabled-commands").join(&filename).is_file() {
+                Some(false)
+            } else {
+                None
+            }
+        }
+        ChangeTarget::Config => base_dir.join("skillsmanager-config.json").is_file().then_some(true),
+    }
+}
+
+// 監視開始時点で存在するスキル・コマンド
+fn snapshot(base_dir: &Path) -> HashSet<Item> {
+    let mut items = HashSet::new();
+    for dir in ["skills", "disabled-skills", "commands", "disabled-commands"] {
+        if let Ok(entries) = fs::read_dir(base_dir.join(dir)) {
+            for entry in entries.filter_map(|e| e.ok()) {
+                if let Some(item) = classify(base_dir, &entry.path()) {
+                    if current_state(base_dir, &item).is_some() {
+                        items.insert(item);
+                    }
+                }
+            }
+        }
+    }
+    items
+}
+
+fn start(
+    base_dir: PathBuf,
+    on_change: impl Fn(Vec<FsChange>) + Send + 'static,
+) -> notify_debouncer_mini::notify::Result<Debouncer<RecommendedWatcher>> {
+    let mut known = snapshot(&base_dir);
+    let watched_dir = base_dir.clone();
+
+    let mut debouncer = new_debouncer(DEBOUNCE, move |result: DebounceEventResult| {
+        let events = match result {
+            Ok(events) => events,
+            Err(e) => {
+                log::warn!("File watcher error: {}", e);
+                return;
+            }
+        };
+
+        let mut affected: Vec<Item> = Vec::new();
+        for event in events {
+            if let Some(item) = classify(&base_dir, &event.path) {
+                if !affected.contains(&item) {
+                    affected.push(item);
+                }
+            }
+        }
+
+        let mut changes = Vec::new();
+        for item in affected {
+            let state = current_state(&base_dir, &item);
+            let existed = item.0 == ChangeTarget::Config || known.contains(&item);
+            let change = match (existed, state.is_some()) {
+                (false, true) => ChangeKind::Added,
+                (true, true) => ChangeKind::Changed,
+                (true, false) => ChangeKind::Removed,
+                (false, false) => continue,
+            };
+
+            if state.is_some() {
+                known.insert(item.clone());
+            } else {
+                known.remove(&item);
+            }
+
+            let (target, name) = item;
+            changes.push(FsChange {
+                target,
+                name: (target != ChangeTarget::Config).then_some(name),
+                change,
+                enabled: if target == ChangeTarget::Config { None } else { state },
+            });
+        }
+
+        if !changes.is_empty() {
+            on_change(changes);
+        }
+    })?;
+
+    // まだ存在しないディレクトリの作成も拾えるよう、ベースディレクトリごと監視する
+    debouncer.watcher().watch(&watched_dir, RecursiveMode::Recursive)?;
+    Ok(debouncer)
+}
@@ -1,5 +1,6 @@
 import { useState, useMemo, useEffect, useCallback } from 'react';
 import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
 import { exit } from '@tauri-apps/plugin-process';
 import { Header } from './components/Header';
 import { CategoryTabs } from './components/CategoryTabs';
@@ -8,7 +9,9 @@ import { SkillPreview } from './components/SkillPreview';
 import { SlashCommandPreview } from './components/SlashCommandPreview';
 import { CategoryEditor } from './components/CategoryEditor';
 import { ProjectSelector } from './components/ProjectSelector';
+import { DuplicateDialog } from './components/DuplicateDialog';
 import { useSkills } from './hooks/useSkills';
+import { errorMessage } from './utils/errors';
 import type { SkillFile, SlashCommand } from './types';
 
 // 開発モード: レイアウト確認用（フォルダ選択をスキップ）
@@ -24,18 +27,20 @@ function App() {
   const [searchQuery, setSearchQuery] = useState('');
   const [selectedFile, setSelectedFile] = useState<SkillFile | null>(null);
 
+  // 開いているプロジェクトの情報を取得（起動時・プロジェクト切り替え時）
+  const checkSetup = useCallback(async () => {
+    const setup = await invoke<boolean>('check_setup');
+    setIsSetup(setup);
+    const type = await invoke<AgentType>('get_agent_type');
+    setAgentType(type);
+    const available = await invoke<string[]>('get_available_agents');
+    setAvailableAgents(available as AgentType[]);
+  }, []);
+
   useEffect(() => {
     if (DEV_MODE) return; // 開発モードではチェックをスキップ
-    const checkSetup = async () => {
-      const setup = await invoke<boolean>('check_setup');
-      setIsSetup(setup);
-      const type = await invoke<AgentType>('get_agent_type');
-      setAgentType(type);
-      const available = await invoke<string[]>('get_available_agents');
-      setAvailableAgents(available as AgentType[]);
-    };
     checkSetup();
-  }, []);
+  }, [checkSetup]);
 
   const {
     skills,
@@ -63,6 +68,10 @@ function App() {
     removeCategory,
     renameCategory,
     reorderCategories,
+    saveProfile,
+    applyProfile,
+    toggleConflict,
+    resolveToggleConflict,
     reload,
     loading,
     error
@@ -79,15 +88,59 @@ function App() {
     return () => clearInterval(interval);
   }, [isSetup, reload]);
 
+  // 別のプロジェクトを指定して再度起動された場合は、このウィンドウで開き直す
+  useEffect(() => {
+    if (DEV_MODE) return;
+    const unlisten = listen<string | null>('project-changed', async () => {
+      setSelectedFile(null);
+      await checkSetup();
+      await reload();
+    });
+    return () => {
+      unlisten.then(fn => fn());
+    };
+  }, [checkSetup, reload]);
+
   // エージェントタイプ切り替え
   const handleSwitchAgent = useCallback(async (target: AgentType) => {
     try {
-      await invoke('switch_agent_type', { target });
-      await exit(0);
+      const relaunched = await invoke<boolean>('switch_agent_type', { target });
+      if (relaunched) {
+        await exit(0);
+        return;
+      }
+      await checkSetup();
+      await reload();
     } catch (e) {
       console.error('Failed to switch agent:', e);
     }
-  }, []);
+  }, [checkSetup, reload]);
+
+  // プロファイル（有効にするスキル・コマンドの組み合わせ）
+  const handleSaveProfile = useCallback(async () => {
+    const name = window.prompt('現在有効なスキル・コマンドをプロファイルとして保存します。名前を入力してください')?.trim();
+    if (!name) return;
+    if (config.profiles?.[name] && !window.confirm(`プロファイル「${name}」を上書きしますか？`)) return;
+    try {
+      await saveProfile(name);
+    } catch (e) {
+      window.alert(errorMessage(e));
+    }
+  }, [config.profiles, saveProfile]);
+
+  const handleApplyProfile = useCallback(async (name: string) => {
+    try {
+      const changes = await applyProfile(name);
+      if (!changes) return;
+      const missing = [...changes.missingSkills, ...changes.missingCommands.map(c => `/${c}`)];
+      if (missing.length > 0) {
+        window.alert(`見つからなかったスキル・コマンド: ${missing.join(', ')}`);
+      }
+    } catch (e) {
+      // 失敗した場合は何も変更されていない
+      window.alert(`プロファイルを適用できませんでした: ${errorMessage(e)}`);
+    }
+  }, [applyProfile]);
 
   // 検索フィルター
   const filteredSkills = useMemo(() => {
@@ -126,7 +179,7 @@ function App() {
 
   // .claudeディレクトリ外で起動された場合
   if (isSetup === false) {
-    return <ProjectSelector onUseCurrentDir={() => setIsSetup(true)} />;
+    return <ProjectSelector onProjectOpened={checkSetup} />;
   }
 
   if (loading) {
@@ -161,6 +214,9 @@ function App() {
         onSearchChange={setSearchQuery}
         totalSkills={totalSkills}
         enabledSkills={enabledSkills}
+        profileNames={Object.keys(config.profiles ?? {})}
+        onApplyProfile={handleApplyProfile}
+        onSaveProfile={handleSaveProfile}
       />
 
       <CategoryTabs
@@ -208,6 +264,7 @@ function App() {
             agentType={agentType}
             availableAgents={availableAgents}
             onSwitchAgent={handleSwitchAgent}
+            onScopeChanged={reload}
           />
         </div>
 
@@ -234,6 +291,10 @@ function App() {
         </div>
       </div>
 
+      {toggleConflict && (
+        <DuplicateDialog conflict={toggleConflict} onResolve={resolveToggleConflict} />
+      )}
+
       {showSettings && (
         <CategoryEditor
           categories={categories}
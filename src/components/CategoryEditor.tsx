@@ -1,5 +1,6 @@
 import { useState, useEffect } from 'react';
 import { invoke } from '@tauri-apps/api/core';
+import { errorMessage } from '../utils/errors';
 
 // 開発モード判定
 const DEV_MODE = import.meta.env.VITE_DEV_MODE === 'true';
@@ -50,7 +51,7 @@ export function CategoryEditor({
       await invoke('copy_app_to_commands');
       setAddedToCommands(true);
     } catch (e) {
-      setErrorMessage(`コマンドの追加に失敗しました: ${e}`);
+      setErrorMessage(`コマンドの追加に失敗しました: ${errorMessage(e)}`);
       setTimeout(() => setErrorMessage(null), 3000);
     } finally {
       setAddingToCommands(false);
@@ -0,0 +1,56 @@
+import type { DuplicateResolution, ToggleConflict } from '../types';
+
+interface Props {
+  conflict: ToggleConflict;
+  onResolve: (resolution: DuplicateResolution | null) => void;
+}
+
+const LABELS: Record<DuplicateResolution, { title: string; description: string }> = {
+  'keep-enabled': { title: '有効な方を残す', description: '無効側のものはゴミ箱に移動します' },
+  'keep-disabled': { title: '無効な方を残す', description: '有効側のものはゴミ箱に移動します' },
+  merge: { title: '統合する', description: '無効側にしかないファイルを有効側にコピーし、無効側はゴミ箱に移動します' },
+};
+
+// 有効・無効の両方に同名のスキル・コマンドがあって切り替えられないときの確認ダイアログ
+export function DuplicateDialog({ conflict, onResolve }: Props) {
+  const kindLabel = conflict.kind === 'skill' ? 'スキル' : 'コマンド';
+
+  return (
+    <div className="fixed inset-0 bg-black/50 flex items-center justify-center z-50">
+      <div className="bg-white rounded-xl shadow-2xl w-full max-w-md mx-4 overflow-hidden">
+        <div className="px-6 py-4 bg-gradient-to-r from-slate-700 to-slate-800">
+          <h2 className="text-lg font-bold text-white">同名の{kindLabel}が2つあります</h2>
+        </div>
+        <div className="p-6">
+          <p className="text-sm text-gray-600 mb-2">
+            {kindLabel}「{conflict.name}」が有効・無効の両方にあるため切り替えられません。どちらを残しますか？
+          </p>
+          <ul className="text-xs text-gray-500 mb-4 space-y-1 break-all">
+            <li>有効: {conflict.enabledPath}</li>
+            <li>無効: {conflict.disabledPath}</li>
+          </ul>
+          <div className="flex flex-col gap-2">
+            {conflict.resolutions.map(resolution => (
+              <button
+                key={resolution}
+                onClick={() => onResolve(resolution)}
+                className="px-4 py-2 text-left bg-gray-100 hover:bg-gray-200 rounded-lg transition-colors"
+              >
+                <div className="text-sm font-medium text-gray-800">{LABELS[resolution].title}</div>
+                <div className="text-xs text-gray-500">{LABELS[resolution].description}</div>
+              </button>
+            ))}
+          </div>
+        </div>
+        <div className="px-6 py-4 bg-gray-50 border-t flex justify-end">
+          <button
+            onClick={() => onResolve(null)}
+            className="px-6 py-2.5 bg-gray-200 text-gray-700 font-medium rounded-xl hover:bg-gray-300 transition-colors"
+          >
+            キャンセル
+          </button>
+        </div>
+      </div>
+    </div>
+  );
+}
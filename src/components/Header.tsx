@@ -5,6 +5,9 @@ interface HeaderProps {
   onSearchChange: (query: string) => void;
   totalSkills: number;
   enabledSkills: number;
+  profileNames?: string[];
+  onApplyProfile?: (name: string) => void;
+  onSaveProfile?: () => void;
 }
 
 export function Header({
@@ -14,6 +17,9 @@ export function Header({
   onSearchChange,
   totalSkills,
   enabledSkills,
+  profileNames = [],
+  onApplyProfile,
+  onSaveProfile,
 }: HeaderProps) {
 
   return (
@@ -46,6 +52,33 @@ export function Header({
         </div>
 
         <div className="flex items-center gap-3">
+          {onApplyProfile && onSaveProfile && (
+            <div className="flex items-center gap-1">
+              <select
+                value=""
+                onChange={(e) => e.target.value && onApplyProfile(e.target.value)}
+                disabled={profileNames.length === 0}
+                className="py-2 pl-3 pr-8 bg-slate-700/50 border border-slate-600 rounded-lg text-sm text-white focus:outline-none focus:ring-2 focus:ring-blue-400 disabled:opacity-50"
+                title="プロファイルのスキル・コマンドだけを有効にする"
+              >
+                <option value="">プロファイルを適用...</option>
+                {profileNames.map(name => (
+                  <option key={name} value={name}>{name}</option>
+                ))}
+              </select>
+              <button
+                onClick={onSaveProfile}
+                className="p-2.5 hover:bg-slate-700 rounded-lg transition-colors group"
+                aria-label="現在の状態をプロファイルとして保存"
+                title="現在の状態をプロファイルとして保存"
+              >
+                <svg className="w-5 h-5 text-slate-300 group-hover:text-white transition-colors" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+                  <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M5 5a2 2 0 012-2h10a2 2 0 012 2v16l-7-3.5L5 21V5z" />
+                </svg>
+              </button>
+            </div>
+          )}
+
           <div className="relative">
             <svg className="w-5 h-5 text-slate-400 absolute left-3 top-1/2 -translate-y-1/2" fill="none" stroke="currentColor" viewBox="0 0 24 24">
               <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M21 21l-6-6m2-5a7 7 0 11-14 0 7 7 0 0114 0z" />
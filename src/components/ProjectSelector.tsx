@@ -1,34 +1,88 @@
-import { useState } from 'react';
+import { useState, useEffect } from 'react';
 import { open } from '@tauri-apps/plugin-dialog';
 import { invoke } from '@tauri-apps/api/core';
 import { exit } from '@tauri-apps/plugin-process';
+import type { AgentDirSummary, RecentProject } from '../types';
+import { errorMessage } from '../utils/errors';
 
 interface Props {
-  onUseCurrentDir: () => void;
+  onProjectOpened: () => void;
 }
 
-export function ProjectSelector({ onUseCurrentDir }: Props) {
+export function ProjectSelector({ onProjectOpened }: Props) {
   const [installing, setInstalling] = useState(false);
   const [error, setError] = useState<string | null>(null);
+  const [recentProjects, setRecentProjects] = useState<RecentProject[]>([]);
+  const [discovered, setDiscovered] = useState<AgentDirSummary[] | null>(null);
 
-  const handleSelectFolder = async () => {
+  useEffect(() => {
+    invoke<RecentProject[]>('get_recent_projects').then(setRecentProjects).catch(() => {});
+  }, []);
+
+  const selectFolder = () => open({
+    directory: true,
+    multiple: false,
+    title: 'スキルを管理したいプロジェクトを選んでください',
+  });
+
+  // path を省略するとカレントディレクトリを開く
+  const openProject = async (path?: string, agent?: string) => {
+    try {
+      setError(null);
+      await invoke('open_project', { path, agent });
+      onProjectOpened();
+    } catch (err) {
+      console.error('Failed to open project:', err);
+      setError(errorMessage(err));
+    }
+  };
+
+  const handleOpenFolder = async () => {
+    const selected = await selectFolder();
+    if (selected && typeof selected === 'string') {
+      await openProject(selected);
+    }
+  };
+
+  // モノレポなど、フォルダ内の .claude / .codex をまとめて探す
+  const handleDiscover = async () => {
+    const selected = await selectFolder();
+    if (selected && typeof selected === 'string') {
+      try {
+        setError(null);
+        setDiscovered(await invoke<AgentDirSummary[]>('discover_agent_dirs', { root: selected }));
+      } catch (err) {
+        console.error('Failed to discover agent directories:', err);
+        setError(errorMessage(err));
+      }
+    }
+  };
+
+  const handleRemoveRecent = async (path: string) => {
+    await invoke('remove_recent_project', { path }).catch(() => {});
+    setRecentProjects(prev => prev.filter(p => p.path !== path));
+  };
+
+  const handleInstallToFolder = async () => {
     try {
-      const selected = await open({
-        directory: true,
-        multiple: false,
-        title: 'スキルを管理したいプロジェクトを選んでください',
-      });
+      const selected = await selectFolder();
 
       if (selected && typeof selected === 'string') {
         setInstalling(true);
         setError(null);
-        await invoke('copy_app_to_project', { projectPath: selected });
-        // コピー先で新しいアプリが起動したので、このアプリを終了
-        await exit(0);
+        const relaunched = await invoke<boolean>('copy_app_to_project', { projectPath: selected });
+        if (relaunched) {
+          // コピー先で新しいアプリが起動したので、このアプリを終了
+          await exit(0);
+        } else {
+          // 既にそのフォルダのアプリで起動している場合は、このウィンドウで開く
+          setInstalling(false);
+          onProjectOpened();
+        }
       }
     } catch (err) {
       console.error('Failed to install:', err);
-      setError(String(err));
+      setError(errorMessage(err));
       setInstalling(false);
     }
   };
@@ -49,25 +103,92 @@ export function ProjectSelector({ onUseCurrentDir }: Props) {
 
         <div className="flex flex-col gap-3">
           <button
-            onClick={handleSelectFolder}
+            onClick={handleOpenFolder}
             disabled={installing}
             className="px-6 py-3 bg-blue-600 text-white rounded-lg hover:bg-blue-700 transition-colors font-medium disabled:opacity-50 disabled:cursor-not-allowed"
           >
-            {installing ? 'インストール中...' : 'フォルダを選択'}
+            フォルダを開く
           </button>
 
           <button
-            onClick={onUseCurrentDir}
+            onClick={handleInstallToFolder}
+            disabled={installing}
+            className="px-6 py-3 bg-gray-200 text-gray-700 rounded-lg hover:bg-gray-300 transition-colors font-medium disabled:opacity-50 disabled:cursor-not-allowed"
+          >
+            {installing ? 'インストール中...' : 'フォルダの .claude/ にアプリをコピー'}
+          </button>
+
+          <button
+            onClick={() => openProject()}
             disabled={installing}
             className="px-6 py-3 bg-gray-200 text-gray-700 rounded-lg hover:bg-gray-300 transition-colors font-medium disabled:opacity-50 disabled:cursor-not-allowed"
           >
             現在のディレクトリを使用
           </button>
+
+          <button
+            onClick={handleDiscover}
+            disabled={installing}
+            className="px-6 py-3 bg-gray-200 text-gray-700 rounded-lg hover:bg-gray-300 transition-colors font-medium disabled:opacity-50 disabled:cursor-not-allowed"
+          >
+            フォルダ内の .claude / .codex を探す
+          </button>
         </div>
 
-        <p className="text-sm text-gray-500 mt-4">
-          選択したフォルダの .claude/ にアプリがコピーされます
-        </p>
+        {discovered && (
+          <div className="mt-6 text-left">
+            <h2 className="text-sm font-medium text-gray-500 mb-2">見つかったディレクトリ（{discovered.length}件）</h2>
+            {discovered.length === 0 ? (
+              <p className="text-sm text-gray-500">.claude / .codex ディレクトリが見つかりませんでした</p>
+            ) : (
+              <ul className="divide-y divide-gray-100 border border-gray-200 rounded-lg max-h-64 overflow-y-auto">
+                {discovered.map(dir => (
+                  <li key={dir.path}>
+                    <button
+                      onClick={() => openProject(dir.path)}
+                      disabled={installing}
+                      className="w-full px-3 py-2 text-left hover:bg-gray-50 disabled:opacity-50"
+                      title={dir.path}
+                    >
+                      <div className="text-sm text-gray-800 truncate">{dir.path}</div>
+                      <div className="text-xs text-gray-500">
+                        スキル {dir.enabledSkills}/{dir.enabledSkills + dir.disabledSkills} ・ コマンド {dir.enabledCommands}/{dir.enabledCommands + dir.disabledCommands}
+                      </div>
+                    </button>
+                  </li>
+                ))}
+              </ul>
+            )}
+          </div>
+        )}
+
+        {recentProjects.length > 0 && (
+          <div className="mt-6 text-left">
+            <h2 className="text-sm font-medium text-gray-500 mb-2">最近のプロジェクト</h2>
+            <ul className="divide-y divide-gray-100 border border-gray-200 rounded-lg">
+              {recentProjects.map(project => (
+                <li key={project.path} className="flex items-center">
+                  <button
+                    onClick={() => openProject(project.path, project.agent)}
+                    disabled={installing}
+                    className="flex-1 min-w-0 px-3 py-2 text-left hover:bg-gray-50 disabled:opacity-50"
+                    title={project.path}
+                  >
+                    <div className="text-sm text-gray-800 truncate">{project.path}</div>
+                    <div className="text-xs text-gray-500">{project.agent === 'codex' ? 'Codex' : 'Claude Code'} ・ {project.openedAt}</div>
+                  </button>
+                  <button
+                    onClick={() => handleRemoveRecent(project.path)}
+                    className="px-3 py-2 text-gray-400 hover:text-gray-600"
+                    title="一覧から削除"
+                  >
+                    ×
+                  </button>
+                </li>
+              ))}
+            </ul>
+          </div>
+        )}
       </div>
     </div>
   );
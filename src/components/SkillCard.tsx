@@ -2,6 +2,7 @@ import { useState, useEffect, useRef } from 'react';
 import { writeText } from '@tauri-apps/plugin-clipboard-manager';
 import { invoke } from '@tauri-apps/api/core';
 import type { Skill, SkillFile, SkillConflictInfo } from '../types';
+import { errorMessage, isConflict } from '../utils/errors';
 
 type AgentType = 'claude' | 'codex' | 'none';
 
@@ -14,9 +15,10 @@ interface SkillCardProps {
   onFileSelect?: (file: SkillFile | null) => void;  // null = SKILL.md
   selectedFile?: SkillFile | null;
   agentType?: AgentType;
+  onScopeChanged?: () => void;
 }
 
-export function SkillCard({ skill, isSelected, onSelect, onToggle, searchHighlight, onFileSelect, selectedFile, agentType = 'none' }: SkillCardProps) {
+export function SkillCard({ skill, isSelected, onSelect, onToggle, searchHighlight, onFileSelect, selectedFile, agentType = 'none', onScopeChanged }: SkillCardProps) {
   const [copiedPath, setCopiedPath] = useState(false);
   const [contextMenu, setContextMenu] = useState<{ x: number; y: number } | null>(null);
   const [copyMessage, setCopyMessage] = useState<string | null>(null);
@@ -73,7 +75,7 @@ export function SkillCard({ skill, isSelected, onSelect, onToggle, searchHighlig
         setTimeout(() => setCopyMessage(null), 2000);
       }
     } catch (err) {
-      setCopyMessage(String(err));
+      setCopyMessage(errorMessage(err));
       setTimeout(() => setCopyMessage(null), 3000);
     }
   };
@@ -85,7 +87,7 @@ export function SkillCard({ skill, isSelected, onSelect, onToggle, searchHighlig
       setCopyMessage('上書きコピーしました');
       setTimeout(() => setCopyMessage(null), 2000);
     } catch (err) {
-      setCopyMessage(String(err));
+      setCopyMessage(errorMessage(err));
       setTimeout(() => setCopyMessage(null), 3000);
     }
   };
@@ -94,6 +96,32 @@ export function SkillCard({ skill, isSelected, onSelect, onToggle, searchHighlig
     setConflictDialog(null);
   };
 
+  // プロジェクト ↔ ユーザー（~/.claude）のコピー・移動
+  const otherScopeLabel = skill.scope === 'user' ? 'プロジェクト' : 'ユーザー設定';
+  const handleTransfer = async (mode: 'copy' | 'move', force = false) => {
+    setContextMenu(null);
+    try {
+      await invoke('transfer_skill', {
+        skillName: skill.name,
+        from: skill.scope,
+        to: skill.scope === 'user' ? 'project' : 'user',
+        mode,
+        force,
+      });
+      setCopyMessage(mode === 'copy' ? 'コピーしました' : '移動しました');
+      setTimeout(() => setCopyMessage(null), 2000);
+      onScopeChanged?.();
+    } catch (err) {
+      // 移動先に同名のスキルがあれば確認して上書き
+      if (!force && isConflict(err, 'alreadyExists') && window.confirm(`${err.message}\n上書きしますか？`)) {
+        await handleTransfer(mode, true);
+        return;
+      }
+      setCopyMessage(errorMessage(err));
+      setTimeout(() => setCopyMessage(null), 3000);
+    }
+  };
+
   const targetAgent = agentType === 'claude' ? 'Codex' : agentType === 'codex' ? 'Claude Code' : null;
 
   const highlightText = (text: string, query?: string) => {
@@ -133,7 +161,7 @@ export function SkillCard({ skill, isSelected, onSelect, onToggle, searchHighlig
         {/* コピーメッセージ */}
         {copyMessage && (
           <div className={`absolute top-2 right-2 px-2 py-1 text-white text-xs rounded shadow-lg z-10 ${
-            copyMessage === 'コピーしました' ? 'bg-green-500' : 'bg-red-500'
+            copyMessage === 'コピーしました' || copyMessage === '移動しました' ? 'bg-green-500' : 'bg-red-500'
           }`}>
             {copyMessage}
           </div>
@@ -172,6 +200,21 @@ export function SkillCard({ skill, isSelected, onSelect, onToggle, searchHighlig
             }`}>
               {skill.enabled ? '有効' : '無効'}
             </span>
+            {skill.scope === 'user' && (
+              <span className="shrink-0 inline-flex items-center px-2 py-0.5 text-xs font-medium rounded-full bg-amber-100 text-amber-700" title="~/.claude のスキル">
+                ユーザー
+              </span>
+            )}
+            {skill.duplicate && (
+              <span className="shrink-0 inline-flex items-center px-2 py-0.5 text-xs font-medium rounded-full bg-red-100 text-red-700" title="skills/ と disabled-skills/ の両方に同名のスキルがあります">
+                重複
+              </span>
+            )}
+            {skill.shadows && (
+              <span className="shrink-0 inline-flex items-center px-2 py-0.5 text-xs font-medium rounded-full bg-orange-100 text-orange-700" title="同名のユーザーのスキルより優先されます">
+                ユーザー版を上書き
+              </span>
+            )}
             {hasFiles && (
               <span className="shrink-0 text-xs text-gray-400">
                 +{skill.files.length}ファイル
@@ -278,6 +321,24 @@ export function SkillCard({ skill, isSelected, onSelect, onToggle, searchHighlig
               {targetAgent}にコピー
             </button>
           )}
+          <button
+            onClick={() => handleTransfer('copy')}
+            className="w-full px-4 py-2 text-left text-sm text-gray-700 hover:bg-gray-100 flex items-center gap-2"
+          >
+            <svg className="w-4 h-4" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+              <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M8 7v8a2 2 0 002 2h6M8 7V5a2 2 0 012-2h4.586a1 1 0 01.707.293l4.414 4.414a1 1 0 01.293.707V15a2 2 0 01-2 2h-2M8 7H6a2 2 0 00-2 2v10a2 2 0 002 2h8a2 2 0 002-2v-2" />
+            </svg>
+            {otherScopeLabel}にコピー
+          </button>
+          <button
+            onClick={() => handleTransfer('move')}
+            className="w-full px-4 py-2 text-left text-sm text-gray-700 hover:bg-gray-100 flex items-center gap-2"
+          >
+            <svg className="w-4 h-4" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+              <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M13 7l5 5m0 0l-5 5m5-5H6" />
+            </svg>
+            {otherScopeLabel}に移動
+          </button>
         </div>
       )}
 
@@ -1,7 +1,8 @@
 import { useState, useEffect } from 'react';
 import { invoke } from '@tauri-apps/api/core';
 import { writeText } from '@tauri-apps/plugin-clipboard-manager';
-import type { Skill, SkillFile } from '../types';
+import type { Skill, SkillFile, FileContent } from '../types';
+import { errorMessage, isConflict } from '../utils/errors';
 
 interface SkillPreviewProps {
   skill: Skill | null;
@@ -23,6 +24,7 @@ export function SkillPreview({
   onFileSelect
 }: SkillPreviewProps) {
   const [fileContent, setFileContent] = useState<string>('');
+  const [fileToken, setFileToken] = useState<string>('');
   const [loadingFile, setLoadingFile] = useState(false);
   const [currentDir, setCurrentDir] = useState<SkillFile | null>(null);
   const [dirFiles, setDirFiles] = useState<SkillFile[]>([]);
@@ -31,6 +33,7 @@ export function SkillPreview({
   const [editContent, setEditContent] = useState<string>('');
   const [saving, setSaving] = useState(false);
   const [skillContent, setSkillContent] = useState<string>('');
+  const [skillToken, setSkillToken] = useState<string>('');
   const [copiedPath, setCopiedPath] = useState<string | null>(null);
 
   const handleCopyPath = async (path: string) => {
@@ -46,26 +49,35 @@ export function SkillPreview({
   // スキルが変わったらコンテンツをリセット
   useEffect(() => {
     setSkillContent(skill?.content || '');
+    setSkillToken(skill?.content_token || '');
     setFileContent('');
     setCurrentDir(null);
     setDirFiles([]);
     setPathHistory([]);
     setIsEditing(false);
     setEditContent('');
-  }, [skill?.name, skill?.content]);
+  }, [skill?.name]);
+
+  // ディスク上で変更されたら表示を更新する。編集中は書きかけの内容を残し、保存時の競合チェックに任せる
+  useEffect(() => {
+    if (isEditing) return;
+    setSkillContent(skill?.content || '');
+    setSkillToken(skill?.content_token || '');
+  }, [skill?.content, skill?.content_token]);
 
   // 選択ファイルが変わったらファイル内容を読み込む
   useEffect(() => {
     if (selectedFile && !selectedFile.is_directory) {
       setLoadingFile(true);
-      invoke<string>('read_file', { path: selectedFile.path })
-        .then(content => {
-          setFileContent(content);
+      invoke<FileContent>('read_file', { path: selectedFile.path })
+        .then(file => {
+          setFileContent(file.content);
+          setFileToken(file.token);
           setCurrentDir(null);
           setDirFiles([]);
         })
         .catch(err => {
-          setFileContent(`Error loading file: ${err}`);
+          setFileContent(`Error loading file: ${errorMessage(err)}`);
         })
         .finally(() => {
           setLoadingFile(false);
@@ -140,23 +152,48 @@ export function SkillPreview({
     if (!skill) return;
 
     setSaving(true);
-    try {
-      const pathToSave = selectedFile ? selectedFile.path : skill.path;
-      await invoke('write_file', { path: pathToSave, content: editContent });
+    const pathToSave = selectedFile ? selectedFile.path : skill.path;
+    const expectedToken = selectedFile ? fileToken : skillToken;
 
-      // 保存後にコンテンツを更新
+    const applySaved = (content: string, token: string) => {
       if (selectedFile) {
-        setFileContent(editContent);
+        setFileContent(content);
+        setFileToken(token);
       } else {
         // SKILL.mdの場合はローカル状態を更新
-        setSkillContent(editContent);
+        setSkillContent(content);
+        setSkillToken(token);
       }
+    };
 
+    const save = async (token: string) => {
+      const newToken = await invoke<string>('write_file', { path: pathToSave, content: editContent, expectedToken: token });
+      applySaved(editContent, newToken);
       setIsEditing(false);
       setEditContent('');
+    };
+
+    try {
+      await save(expectedToken);
     } catch (err) {
-      console.error('Failed to save:', err);
-      alert(`保存に失敗しました: ${err}`);
+      if (isConflict(err, 'modifiedOnDisk')) {
+        // 開いている間に他のエディタやClaude Codeが書き換えた
+        if (confirm('このファイルは開いた後にディスク上で変更されています。上書きしますか？\n（キャンセルするとディスク上の内容を表示します）')) {
+          try {
+            await save(err.current.token);
+          } catch (retryErr) {
+            console.error('Failed to save:', retryErr);
+            alert(`保存に失敗しました: ${errorMessage(retryErr)}`);
+          }
+        } else {
+          applySaved(err.current.content, err.current.token);
+          setIsEditing(false);
+          setEditContent('');
+        }
+      } else {
+        console.error('Failed to save:', err);
+        alert(`保存に失敗しました: ${errorMessage(err)}`);
+      }
     }
     setSaving(false);
   };
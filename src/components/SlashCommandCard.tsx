@@ -111,6 +111,21 @@ export function SlashCommandCard({ command, isSelected, onSelect, onToggle, sear
             }`}>
               {command.enabled ? '有効' : '無効'}
             </span>
+            {command.scope === 'user' && (
+              <span className="shrink-0 inline-flex items-center px-2 py-0.5 text-xs font-medium rounded-full bg-amber-100 text-amber-700" title="~/.claude のコマンド">
+                ユーザー
+              </span>
+            )}
+            {command.duplicate && (
+              <span className="shrink-0 inline-flex items-center px-2 py-0.5 text-xs font-medium rounded-full bg-red-100 text-red-700" title="commands/ と disabled-commands/ の両方に同名のコマンドがあります">
+                重複
+              </span>
+            )}
+            {command.shadows && (
+              <span className="shrink-0 inline-flex items-center px-2 py-0.5 text-xs font-medium rounded-full bg-orange-100 text-orange-700" title="同名のユーザーのコマンドより優先されます">
+                ユーザー版を上書き
+              </span>
+            )}
           </div>
           <p className="text-sm text-gray-500 truncate mt-0.5">
             {highlightText(command.description, searchHighlight)}
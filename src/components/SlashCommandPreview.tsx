@@ -2,6 +2,7 @@ import { useState, useEffect } from 'react';
 import { invoke } from '@tauri-apps/api/core';
 import { writeText } from '@tauri-apps/plugin-clipboard-manager';
 import type { SlashCommand } from '../types';
+import { errorMessage, isConflict } from '../utils/errors';
 
 interface SlashCommandPreviewProps {
   command: SlashCommand | null;
@@ -21,6 +22,7 @@ export function SlashCommandPreview({
   const [isEditing, setIsEditing] = useState(false);
   const [editContent, setEditContent] = useState<string>('');
   const [commandContent, setCommandContent] = useState<string>('');
+  const [commandToken, setCommandToken] = useState<string>('');
   const [saving, setSaving] = useState(false);
   const [copiedPath, setCopiedPath] = useState(false);
 
@@ -38,9 +40,17 @@ export function SlashCommandPreview({
   // コマンドが変わったらコンテンツをリセット
   useEffect(() => {
     setCommandContent(command?.content || '');
+    setCommandToken(command?.content_token || '');
     setIsEditing(false);
     setEditContent('');
-  }, [command?.name, command?.content]);
+  }, [command?.name]);
+
+  // ディスク上で変更されたら表示を更新する。編集中は書きかけの内容を残し、保存時の競合チェックに任せる
+  useEffect(() => {
+    if (isEditing) return;
+    setCommandContent(command?.content || '');
+    setCommandToken(command?.content_token || '');
+  }, [command?.content, command?.content_token]);
 
   const handleStartEdit = () => {
     setEditContent(commandContent);
@@ -56,14 +66,36 @@ export function SlashCommandPreview({
     if (!command) return;
 
     setSaving(true);
-    try {
-      await invoke('write_file', { path: command.path, content: editContent });
+    const save = async (token: string) => {
+      const newToken = await invoke<string>('write_file', { path: command.path, content: editContent, expectedToken: token });
       setCommandContent(editContent);
+      setCommandToken(newToken);
       setIsEditing(false);
       setEditContent('');
+    };
+
+    try {
+      await save(commandToken);
     } catch (err) {
-      console.error('Failed to save:', err);
-      alert(`保存に失敗しました: ${err}`);
+      if (isConflict(err, 'modifiedOnDisk')) {
+        // 開いている間に他のエディタやClaude Codeが書き換えた
+        if (confirm('このファイルは開いた後にディスク上で変更されています。上書きしますか？\n（キャンセルするとディスク上の内容を表示します）')) {
+          try {
+            await save(err.current.token);
+          } catch (retryErr) {
+            console.error('Failed to save:', retryErr);
+            alert(`保存に失敗しました: ${errorMessage(retryErr)}`);
+          }
+        } else {
+          setCommandContent(err.current.content);
+          setCommandToken(err.current.token);
+          setIsEditing(false);
+          setEditContent('');
+        }
+      } else {
+        console.error('Failed to save:', err);
+        alert(`保存に失敗しました: ${errorMessage(err)}`);
+      }
     }
     setSaving(false);
   };
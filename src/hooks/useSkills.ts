@@ -1,6 +1,24 @@
 import { useState, useMemo, useCallback, useEffect } from 'react';
-import type { Skill, SlashCommand, Config } from '../types';
+import type { Skill, SlashCommand, Config, FsChange, ProfileChanges, Scope, ToggleConflict, DuplicateResolution } from '../types';
 import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import { errorMessage, isConflict } from '../utils/errors';
+
+// 同名のプロジェクトの項目に上書きされているユーザーの項目は表示しない
+const hideShadowed = <T extends { shadowed: boolean }>(items: T[]): T[] => items.filter(item => !item.shadowed);
+
+// 有効・無効の両方に同名のものがあって切り替えられなかった場合は、解決ダイアログ用の情報を返す
+const toToggleConflict = (err: unknown, scope: Scope): ToggleConflict | null =>
+  isConflict(err, 'enabledAndDisabled')
+    ? {
+        kind: err.target === 'command' ? 'command' : 'skill',
+        name: err.name,
+        scope,
+        enabledPath: err.enabledPath,
+        disabledPath: err.disabledPath,
+        resolutions: err.resolutions,
+      }
+    : null;
 
 const normalizeConfig = (loadedSkills: Skill[], loadedCommands: SlashCommand[], loadedConfig: Config): Config => {
   const nextCategories: Record<string, string[]> = {};
@@ -77,7 +95,13 @@ const normalizeConfig = (loadedSkills: Skill[], loadedCommands: SlashCommand[],
     ];
   }
 
-  return { categories: nextCategories, categoryOrder, commandCategories: nextCommandCategories };
+  return {
+    categories: nextCategories,
+    categoryOrder,
+    commandCategories: nextCommandCategories,
+    profiles: loadedConfig.profiles ?? {},
+    locale: loadedConfig.locale,
+  };
 };
 
 export function useSkills(isReady: boolean) {
@@ -89,6 +113,7 @@ export function useSkills(isReady: boolean) {
   const [selectedSlashCommand, setSelectedSlashCommand] = useState<SlashCommand | null>(null);
   const [loading, setLoading] = useState(true);
   const [error, setError] = useState<string | null>(null);
+  const [toggleConflict, setToggleConflict] = useState<ToggleConflict | null>(null);
 
   // リロード関数
   const reload = useCallback(async () => {
@@ -99,7 +124,7 @@ export function useSkills(isReady: boolean) {
       setError(null);
 
       const [loadedSkills, loadedConfig] = await Promise.all([
-        invoke<Skill[]>('load_skills'),
+        invoke<Skill[]>('load_skills').then(hideShadowed),
         invoke<Config>('load_config')
       ]);
 
@@ -108,7 +133,7 @@ export function useSkills(isReady: boolean) {
       // スラッシュコマンドをロード（設定がtrueの場合のみ）
       const shouldLoadCommands = loadedConfig.loadSlashCommands !== false;
       if (shouldLoadCommands) {
-        const loadedCommands = await invoke<SlashCommand[]>('load_slash_commands');
+        const loadedCommands = await invoke<SlashCommand[]>('load_slash_commands').then(hideShadowed);
         setSlashCommands(loadedCommands);
 
         // selectedSlashCommandを新しいデータで更新
@@ -129,7 +154,7 @@ export function useSkills(isReady: boolean) {
         return updated || null;
       });
 
-      const loadedCommands = shouldLoadCommands ? await invoke<SlashCommand[]>('load_slash_commands').catch(() => []) : [];
+      const loadedCommands = shouldLoadCommands ? await invoke<SlashCommand[]>('load_slash_commands').then(hideShadowed).catch(() => []) : [];
       const normalizedConfig = normalizeConfig(loadedSkills, loadedCommands, loadedConfig);
       // loadSlashCommandsを保持
       normalizedConfig.loadSlashCommands = loadedConfig.loadSlashCommands !== false;
@@ -142,7 +167,7 @@ export function useSkills(isReady: boolean) {
       }
     } catch (err) {
       console.error('Failed to load data:', err);
-      setError(String(err));
+      setError(errorMessage(err));
     } finally {
       setLoading(false);
     }
@@ -157,6 +182,17 @@ export function useSkills(isReady: boolean) {
     reload();
   }, [isReady]);
 
+  // エディタやClaude Codeによるファイル変更を検知したら再読み込み
+  useEffect(() => {
+    if (!isReady) return;
+    const unlisten = listen<FsChange[]>('skills-changed', () => {
+      reload();
+    });
+    return () => {
+      unlisten.then(fn => fn());
+    };
+  }, [isReady, reload]);
+
   // Save config when it changes
   const saveConfig = useCallback(async (newConfig: Config) => {
     try {
@@ -247,9 +283,14 @@ export function useSkills(isReady: boolean) {
     const newEnabled = !skill.enabled;
 
     try {
-      await invoke('toggle_skill', { skillName, enabled: newEnabled });
+      await invoke('toggle_skill', { skillName, enabled: newEnabled, scope: skill.scope });
       setEnabledForSkillNames([skillName], newEnabled);
     } catch (err) {
+      const conflict = toToggleConflict(err, skill.scope);
+      if (conflict) {
+        setToggleConflict(conflict);
+        return;
+      }
       console.error('Failed to toggle skill:', err);
     }
   }, [skills, setEnabledForSkillNames]);
@@ -278,7 +319,7 @@ export function useSkills(isReady: boolean) {
     const newEnabled = !command.enabled;
 
     try {
-      await invoke('toggle_slash_command', { commandName, enabled: newEnabled });
+      await invoke('toggle_slash_command', { commandName, enabled: newEnabled, scope: command.scope });
       setSlashCommands(prev => prev.map(c =>
         c.name === commandName ? updateCommandPath(c, newEnabled) : c
       ));
@@ -286,6 +327,11 @@ export function useSkills(isReady: boolean) {
         prev && prev.name === commandName ? updateCommandPath(prev, newEnabled) : prev
       );
     } catch (err) {
+      const conflict = toToggleConflict(err, command.scope);
+      if (conflict) {
+        setToggleConflict(conflict);
+        return;
+      }
       console.error('Failed to toggle slash command:', err);
     }
   }, [slashCommands, updateCommandPath]);
@@ -295,7 +341,7 @@ export function useSkills(isReady: boolean) {
     if (value) {
       // オンにした場合は再読み込み
       try {
-        const loadedCommands = await invoke<SlashCommand[]>('load_slash_commands');
+        const loadedCommands = await invoke<SlashCommand[]>('load_slash_commands').then(hideShadowed);
         setSlashCommands(loadedCommands);
       } catch (err) {
         console.error('Failed to load slash commands:', err);
@@ -308,20 +354,27 @@ export function useSkills(isReady: boolean) {
   }, [updateConfig]);
 
   const setEnabledForCategory = useCallback(async (enabled: boolean) => {
-    const skillNames = config.categories[selectedCategory] || [];
-
-    const results = await Promise.allSettled(
-      skillNames.map(skillName => invoke('toggle_skill', { skillName, enabled }))
-    );
+    // 設定に残っているだけで実在しないスキルは除く
+    const targets = (config.categories[selectedCategory] || [])
+      .map(name => skills.find(s => s.name === name))
+      .filter((skill): skill is Skill => skill !== undefined)
+      .map(skill => ({ name: skill.name, scope: skill.scope }));
 
-    results.forEach((result, index) => {
-      if (result.status === 'rejected') {
-        console.error(`Failed to update skill: ${skillNames[index]}`, result.reason);
+    // まとめて切り替え、途中で失敗したらバックエンドで全部元に戻される
+    try {
+      await invoke('toggle_many', { skills: targets, commands: [], enabled });
+      setEnabledForSkillNames(targets.map(t => t.name), enabled);
+    } catch (err) {
+      // 有効・無効の両方にあるスキルが含まれていた場合は、何も変更せずに解決ダイアログを出す
+      const target = isConflict(err, 'enabledAndDisabled') ? targets.find(t => t.name === err.name) : undefined;
+      const conflict = target ? toToggleConflict(err, target.scope) : null;
+      if (conflict) {
+        setToggleConflict(conflict);
+        return;
       }
-    });
-
-    setEnabledForSkillNames(skillNames, enabled);
-  }, [config.categories, selectedCategory, setEnabledForSkillNames]);
+      console.error('Failed to update skills in category:', err);
+    }
+  }, [config.categories, selectedCategory, skills, setEnabledForSkillNames]);
 
   const enableAllInCategory = useCallback(async () => {
     await setEnabledForCategory(true);
@@ -369,7 +422,14 @@ export function useSkills(isReady: boolean) {
     });
   }, [updateConfig]);
 
-  const deleteSkill = useCallback((skillName: string) => {
+  const deleteSkill = useCallback(async (skillName: string) => {
+    try {
+      // バックエンドでゴミ箱へ移動（カテゴリからも削除される）
+      await invoke('delete_skill', { skillName });
+    } catch (err) {
+      console.error('Failed to delete skill:', err);
+      return;
+    }
     setSkills(prev => prev.filter(skill => skill.name !== skillName));
     updateConfig(prev => {
       const newCategories = { ...prev.categories };
@@ -446,6 +506,47 @@ export function useSkills(isReady: boolean) {
     }));
   }, [updateConfig]);
 
+  // 有効・無効の両方にある同名の項目を1つにする（nullならキャンセル）
+  const resolveToggleConflict = useCallback(async (resolution: DuplicateResolution | null) => {
+    const conflict = toggleConflict;
+    setToggleConflict(null);
+    if (!conflict || !resolution) return;
+    try {
+      await invoke('resolve_duplicate', {
+        kind: conflict.kind,
+        name: conflict.name,
+        scope: conflict.scope,
+        resolution,
+      });
+    } catch (err) {
+      console.error('Failed to resolve duplicate:', err);
+    }
+    await reload();
+  }, [toggleConflict, reload]);
+
+  // プロファイルは設定ファイルに直接保存されるので、読み込み直して反映する
+  const saveProfile = useCallback(async (name: string) => {
+    await invoke('save_profile', { name });
+    await reload();
+  }, [reload]);
+
+  // 有効・無効の両方にある項目があって適用できなかった場合は解決ダイアログを出して null を返す
+  const applyProfile = useCallback(async (name: string): Promise<ProfileChanges | null> => {
+    try {
+      const changes = await invoke<ProfileChanges>('apply_profile', { name });
+      await reload();
+      return changes;
+    } catch (err) {
+      // プロファイルはプロジェクトの項目だけを切り替える
+      const conflict = toToggleConflict(err, 'project');
+      if (conflict) {
+        setToggleConflict(conflict);
+        return null;
+      }
+      throw err;
+    }
+  }, [reload]);
+
   return {
     skills,
     slashCommands,
@@ -473,6 +574,10 @@ export function useSkills(isReady: boolean) {
     removeCategory,
     renameCategory,
     reorderCategories,
+    saveProfile,
+    applyProfile,
+    toggleConflict,
+    resolveToggleConflict,
     reload,
     loading,
     error
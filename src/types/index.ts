@@ -4,6 +4,18 @@ export interface SkillFile {
   is_directory: boolean;  // ディレクトリかどうか
 }
 
+export interface Frontmatter {
+  name?: string | null;
+  description?: string | null;
+  allowedTools?: string[] | null;
+  model?: string | null;
+  argumentHint?: string | null;
+  license?: string | null;
+  metadata?: Record<string, unknown> | null;
+}
+
+export type Scope = 'user' | 'project';  // ~/.claude かプロジェクトの .claude か
+
 export interface Skill {
   name: string;           // フォルダ名
   description: string;    // SKILL.md の description
@@ -11,6 +23,13 @@ export interface Skill {
   content: string;        // SKILL.md の全内容
   path: string;           // ファイルパス
   files: SkillFile[];     // 関連ファイル
+  content_token: string;  // 保存時の競合検出用トークン
+  frontmatter: Frontmatter | null;     // SKILL.md のfrontmatter
+  frontmatter_error: string | null;    // frontmatterの解析エラー
+  scope: Scope;
+  shadows: boolean;       // 同名のユーザーの項目を上書きしている（プロジェクトの項目）
+  shadowed: boolean;      // 同名のプロジェクトの項目に上書きされている（ユーザーの項目）
+  duplicate: boolean;     // 有効・無効の両方に同名のものがある
 }
 
 export interface SlashCommand {
@@ -19,13 +38,49 @@ export interface SlashCommand {
   enabled: boolean;       // 有効/無効
   content: string;        // ファイルの全内容
   path: string;           // ファイルパス
+  content_token: string;  // 保存時の競合検出用トークン
+  frontmatter: Frontmatter | null;     // frontmatter（なければnull）
+  frontmatter_error: string | null;    // frontmatterの解析エラー
+  scope: Scope;
+  shadows: boolean;       // 同名のユーザーの項目を上書きしている（プロジェクトの項目）
+  shadowed: boolean;      // 同名のプロジェクトの項目に上書きされている（ユーザーの項目）
+  duplicate: boolean;     // 有効・無効の両方に同名のものがある
 }
 
 export interface Config {
+  version?: number;  // 設定ファイルのスキーマバージョン
   categories: Record<string, string[]>;
   categoryOrder?: string[];  // カテゴリの表示順序
   loadSlashCommands?: boolean;  // スラッシュコマンドを読み込むか（デフォルト: true）
   commandCategories?: Record<string, string[]>;  // スラッシュコマンドのカテゴリ分け
+  profiles?: Record<string, Profile>;  // 作業モードごとに有効にするスキル・コマンド
+  locale?: 'ja' | 'en';  // バックエンドのメッセージの言語（省略時はOSの言語）
+}
+
+export interface Profile {
+  skills: string[];
+  commands: string[];
+}
+
+export interface ProfileChanges {
+  enabledSkills: string[];
+  disabledSkills: string[];
+  enabledCommands: string[];
+  disabledCommands: string[];
+  missingSkills: string[];    // プロファイルにあるが見つからないもの
+  missingCommands: string[];
+}
+
+export type DuplicateResolution = 'keep-enabled' | 'keep-disabled' | 'merge';
+
+// toggle_skill / toggle_slash_command で有効・無効の両方に同名のものがあったとき
+export interface ToggleConflict {
+  kind: 'skill' | 'command';
+  name: string;
+  scope: Scope;
+  enabledPath: string;
+  disabledPath: string;
+  resolutions: DuplicateResolution[];
 }
 
 export interface SkillConflictInfo {
@@ -35,3 +90,102 @@ export interface SkillConflictInfo {
   sourceModified: string | null;
   targetModified: string | null;
 }
+
+export interface SkillDiagnostic {
+  skill: string;
+  severity: 'error' | 'warning';
+  file: string;
+  line: number | null;
+  rule: string;
+  message: string;
+}
+
+export interface TrashEntry {
+  id: string;
+  name: string;
+  kind: 'skill' | 'command';
+  // プロジェクトのルート（scope が user ならホーム）からの相対パス
+  originalPath: string;
+  scope: Scope;
+  categories: string[];
+  profiles: string[];
+  deletedAt: string;
+}
+
+export interface ExportManifest {
+  exporter: string;
+  exporterVersion: string;
+  exportedAt: string;
+  skills: {
+    name: string;
+    description: string;
+    enabled: boolean;
+    files: { path: string; sha256: string }[];
+  }[];
+}
+
+export interface FsChange {
+  target: 'skill' | 'command' | 'config';
+  name: string | null;
+  change: 'added' | 'changed' | 'removed';
+  enabled: boolean | null;
+}
+
+export interface FileContent {
+  content: string;
+  token: string;
+}
+
+// エラーが何についてのものか
+export type ErrorTarget =
+  | 'skill' | 'command' | 'profile' | 'config' | 'file' | 'directory' | 'archive'
+  | 'agentDir' | 'agent' | 'trashEntry' | 'app' | 'description' | 'category' | 'resolution';
+
+export type ConflictReason =
+  | { reason: 'alreadyExists'; path: string }
+  | { reason: 'enabledAndDisabled'; enabledPath: string; disabledPath: string; resolutions: DuplicateResolution[] }
+  | { reason: 'modifiedOnDisk'; current: FileContent };  // read_file以降に他のアプリが書き換えた
+
+// tauriコマンドが返すエラー。分岐は code（と reason）で行い、message は表示にだけ使う
+export type AppError = { message: string } & (
+  | { code: 'NotInProject'; path: string | null }
+  | { code: 'NotFound'; target: ErrorTarget; name: string }
+  | ({ code: 'Conflict'; target: ErrorTarget; name: string } & ConflictReason)
+  | { code: 'Io'; path: string | null; detail: string }
+  | { code: 'Parse'; target: ErrorTarget; path: string | null; detail: string; backup: string | null }
+  | { code: 'PermissionDenied'; path: string }
+  | { code: 'InvalidInput'; target: ErrorTarget; value: string }
+);
+
+export interface DuplicateAssignment {
+  name: string;
+  categories: string[];
+}
+
+export interface ReconcileReport {
+  orphaned: string[];  // 設定にあるがディスクに存在しない
+  unassigned: string[];  // どのカテゴリにも入っていない
+  duplicated: DuplicateAssignment[];  // 複数のカテゴリに入っている
+}
+
+export interface ConfigReconcileReport {
+  skills: ReconcileReport;
+  commands: ReconcileReport;
+  fixed: boolean;
+}
+
+export interface RecentProject {
+  path: string;  // プロジェクトのルート
+  agent: 'claude' | 'codex';
+  openedAt: string;
+}
+
+export interface AgentDirSummary {
+  path: string;         // .claude / .codex ディレクトリ
+  projectRoot: string;
+  agent: 'claude' | 'codex';
+  enabledSkills: number;
+  disabledSkills: number;
+  enabledCommands: number;
+  disabledCommands: number;
+}
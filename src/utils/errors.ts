@@ -0,0 +1,13 @@
+import type { AppError, ConflictReason } from '../types';
+
+export const isAppError = (err: unknown): err is AppError =>
+  typeof err === 'object' && err !== null && 'code' in err && 'message' in err;
+
+// 表示用のメッセージ（AppError以外はそのまま文字列にする）
+export const errorMessage = (err: unknown): string => (isAppError(err) ? err.message : String(err));
+
+export const isConflict = <R extends ConflictReason['reason']>(
+  err: unknown,
+  reason: R,
+): err is Extract<AppError, { code: 'Conflict' }> & Extract<ConflictReason, { reason: R }> =>
+  isAppError(err) && err.code === 'Conflict' && err.reason === reason;